@@ -3,6 +3,7 @@ use builder_relayer_client_rust::client::RelayClient;
 use builder_relayer_client_rust::signer::DummySigner;
 use builder_relayer_client_rust::types::{OperationType, SafeTransaction};
 use builder_signing_sdk_rs::BuilderApiKeyCreds; // bring trait into scope for .encode()
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() {
@@ -16,7 +17,7 @@ async fn main() {
         passphrase: "demo_pass".into(),
     };
     let client = RelayClient::new("https://relayer.example.com", 137)
-        .with_signer(Box::new(signer.clone()), Box::new(signer))
+        .with_signer(Arc::new(signer))
         .with_builder_api_key(creds);
 
     let tx = SafeTransaction {
@@ -1,14 +1,14 @@
 use builder_relayer_client_rust::client::RelayClient;
 use builder_relayer_client_rust::signer::DummySigner;
 use builder_relayer_client_rust::types::{OperationType, SafeTransaction};
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() {
     let signer =
         DummySigner::new("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef")
             .unwrap();
-    let client = RelayClient::new("https://relayer.example.com", 137)
-        .with_signer(Box::new(signer.clone()), Box::new(signer));
+    let client = RelayClient::new("https://relayer.example.com", 137).with_signer(Arc::new(signer));
 
     let tx = SafeTransaction {
         to: "0x000000000000000000000000000000000000dead".into(),
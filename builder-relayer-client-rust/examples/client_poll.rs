@@ -1,5 +1,5 @@
 use builder_relayer_client_rust::client::RelayClient;
-use builder_relayer_client_rust::types::RelayerTransactionState;
+use builder_relayer_client_rust::types::{PollPolicy, RelayerTransactionState};
 
 #[tokio::main]
 async fn main() {
@@ -10,13 +10,7 @@ async fn main() {
         RelayerTransactionState::StateConfirmed,
     ];
     let out = client
-        .poll_until_state(
-            id,
-            &states,
-            Some(RelayerTransactionState::StateFailed),
-            10,
-            500,
-        )
+        .poll_until_state(id, &states, &PollPolicy::default())
         .await;
     println!("poll result: {:?}", out);
 }
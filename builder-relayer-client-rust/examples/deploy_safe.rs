@@ -10,7 +10,7 @@ async fn main() {
         DummySigner::new("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef")
             .expect("signer");
     let args = SafeTransactionArgs {
-        from: format!("0x{:x}", signer.address()),
+        from: signer.address().await.expect("address"),
         nonce: "1".to_string(),
         chain_id: 137, // polygon example
         transactions: vec![SafeTransaction {
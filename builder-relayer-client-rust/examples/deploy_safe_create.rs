@@ -9,7 +9,7 @@ async fn main() {
             .expect("signer");
 
     let args = SafeCreateTransactionArgs {
-        from: format!("0x{:x}", signer.address()),
+        from: signer.address().await.expect("address"),
         chain_id: 137,                                                      // Polygon
         payment_token: "0x0000000000000000000000000000000000000000".into(), // Zero address for no payment
         payment: "0".into(),
@@ -0,0 +1,36 @@
+use builder_relayer_client_rust::exchange::Exchange;
+use builder_relayer_client_rust::signer::DummySigner;
+/// 紧急撤单示例（CLOB API 不可用时）
+///
+/// 在两个 Exchange 上 incrementNonce，使 Safe 用旧 nonce 签名的所有订单失效；
+/// 单个订单可用 `cancel_orders_onchain(Exchange::Ctf, &[(&order, &signature)])` 撤销
+use builder_relayer_client_rust::{PollPolicy, RelayClient, RelayerTransactionState};
+use builder_signing_sdk_rs::BuilderApiKeyCreds;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let signer = DummySigner::new(&std::env::var("PRIVATE_KEY")?)?;
+    let client = RelayClient::new("https://relayer-v2.polymarket.com/", 137)
+        .with_signer(Arc::new(signer))
+        .with_builder_api_key(BuilderApiKeyCreds {
+            key: std::env::var("BUILDER_API_KEY")?,
+            secret: std::env::var("BUILDER_SECRET")?,
+            passphrase: std::env::var("BUILDER_PASS_PHRASE")?,
+        });
+
+    let resp = client.increment_exchange_nonce(&Exchange::ALL).await?;
+    println!("nonce bump submitted: {}", resp.transaction_id);
+    let state = client
+        .poll_until_state(
+            &resp.transaction_id,
+            &[
+                RelayerTransactionState::StateMined,
+                RelayerTransactionState::StateConfirmed,
+            ],
+            &PollPolicy::default(),
+        )
+        .await?;
+    println!("final state: {:?}", state.transaction().map(|t| &t.state));
+    Ok(())
+}
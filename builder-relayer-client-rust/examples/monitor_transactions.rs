@@ -3,9 +3,10 @@ use builder_relayer_client_rust::signer::DummySigner;
 ///
 /// 演示如何监控和追踪 Relayer 交易状态
 use builder_relayer_client_rust::{
-    OperationType, RelayClient, RelayerTransactionState, SafeTransaction,
+    OperationType, PollPolicy, RelayClient, RelayerTransactionState, SafeTransaction,
 };
 use builder_signing_sdk_rs::BuilderApiKeyCreds;
+use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 
 #[tokio::main]
@@ -39,7 +40,7 @@ async fn initialize_client() -> Result<RelayClient, Box<dyn std::error::Error>>
     let signer = DummySigner::new(&private_key)?;
 
     let relayer_client = RelayClient::new("https://relayer-v2.polymarket.com/", 137)
-        .with_signer(Box::new(signer.clone()), Box::new(signer))
+        .with_signer(Arc::new(signer))
         .with_builder_api_key(BuilderApiKeyCreds {
             key: std::env::var("BUILDER_API_KEY")?,
             secret: std::env::var("BUILDER_SECRET")?,
@@ -61,13 +62,11 @@ async fn auto_wait_example(client: &RelayClient) -> Result<(), Box<dyn std::erro
         .poll_until_state(
             &response.transaction_id,
             &[RelayerTransactionState::StateConfirmed],
-            Some(RelayerTransactionState::StateFailed),
-            30,
-            2000,
+            &PollPolicy::default(),
         )
         .await?;
 
-    match result {
+    match result.confirmed() {
         Some(receipt) => {
             println!("   ✅ 交易已确认!");
             println!("      状态: {}", receipt.state);
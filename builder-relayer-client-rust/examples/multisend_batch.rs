@@ -9,7 +9,7 @@ async fn main() {
     let signer =
         DummySigner::new("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef")
             .unwrap();
-    let base_from = format!("0x{:x}", signer.address());
+    let base_from = signer.address().await.expect("address");
     let tx1 = SafeTransaction {
         to: "0x000000000000000000000000000000000000dead".into(),
         value: "0".into(),
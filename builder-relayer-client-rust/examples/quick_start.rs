@@ -1,7 +1,9 @@
-use builder_relayer_client_rust::builder::safe::AbstractSigner;
+use builder_relayer_client_rust::signer::AbstractSigner;
 use builder_relayer_client_rust::signer::DummySigner;
-use builder_relayer_client_rust::{RelayClient, RelayerTransactionState};
+use builder_relayer_client_rust::{PollPolicy, RelayClient, RelayerTransactionState};
 use builder_signing_sdk_rs::BuilderApiKeyCreds;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// 快速开始示例
 ///
@@ -18,13 +20,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 2. 创建签名器
     let private_key = std::env::var("PRIVATE_KEY").expect("需要设置 PRIVATE_KEY 环境变量");
     let signer = DummySigner::new(&private_key)?;
-    let signer_address = signer.get_address()?;
+    let signer_address = signer.address().await?;
 
     println!("钱包地址: {}", signer_address);
 
     // 3. 创建 Relayer Client
     let relayer_client = RelayClient::new(relayer_url, chain_id)
-        .with_signer(Box::new(signer.clone()), Box::new(signer))
+        .with_signer(Arc::new(signer))
         .with_builder_api_key(BuilderApiKeyCreds {
             key: std::env::var("BUILDER_API_KEY")?,
             secret: std::env::var("BUILDER_SECRET")?,
@@ -45,13 +47,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .poll_until_state(
             &response.transaction_id,
             &[RelayerTransactionState::StateConfirmed],
-            Some(RelayerTransactionState::StateFailed),
-            30,   // 最多轮询 30 次
-            2000, // 每 2 秒轮询一次
+            &PollPolicy::timeout_after(Duration::from_secs(60)),
         )
         .await?;
 
-    if let Some(receipt) = result {
+    if let Some(receipt) = result.confirmed() {
         println!("✅ Safe 部署成功!");
         println!("   交易状态: {}", receipt.state);
         println!("   交易哈希: {}", receipt.transaction_hash);
@@ -1,12 +1,13 @@
-use builder_relayer_client_rust::builder::safe::AbstractSigner;
+use builder_relayer_client_rust::signer::AbstractSigner;
 use builder_relayer_client_rust::signer::DummySigner;
 /// Relayer Client 示例
 ///
 /// 演示如何使用 Polymarket Relayer Client 执行各种操作
 use builder_relayer_client_rust::{
-    OperationType, RelayClient, RelayerTransactionState, SafeTransaction,
+    OperationType, PollPolicy, RelayClient, RelayerTransactionState, SafeTransaction,
 };
 use builder_signing_sdk_rs::BuilderApiKeyCreds;
+use std::sync::Arc;
 
 // Polygon 主网合约地址
 const POLYGON_CHAIN_ID: u64 = 137;
@@ -49,7 +50,7 @@ async fn initialize_client() -> Result<RelayClient, Box<dyn std::error::Error>>
     // 获取配置
     let private_key = std::env::var("PRIVATE_KEY").expect("PRIVATE_KEY 环境变量未设置");
     let signer = DummySigner::new(&private_key)?;
-    let signer_address = signer.get_address()?;
+    let signer_address = signer.address().await?;
 
     println!("   钱包地址: {}", signer_address);
 
@@ -61,7 +62,7 @@ async fn initialize_client() -> Result<RelayClient, Box<dyn std::error::Error>>
 
     // 创建 RelayClient
     let relay_client = RelayClient::new(RELAYER_URL, POLYGON_CHAIN_ID)
-        .with_signer(Box::new(signer.clone()), Box::new(signer))
+        .with_signer(Arc::new(signer))
         .with_builder_api_key(BuilderApiKeyCreds {
             key: api_key,
             secret,
@@ -82,13 +83,11 @@ async fn deploy_safe(client: &RelayClient) -> Result<String, Box<dyn std::error:
         .poll_until_state(
             &response.transaction_id,
             &[RelayerTransactionState::StateConfirmed],
-            Some(RelayerTransactionState::StateFailed),
-            30,
-            2000,
+            &PollPolicy::default(),
         )
         .await?;
 
-    match result {
+    match result.confirmed() {
         Some(receipt) => {
             println!("   交易哈希: {}", receipt.transaction_hash);
             println!("   Safe 地址: {}", receipt.proxy_address);
@@ -121,13 +120,11 @@ async fn approve_token(
         .poll_until_state(
             &response.transaction_id,
             &[RelayerTransactionState::StateConfirmed],
-            Some(RelayerTransactionState::StateFailed),
-            30,
-            2000,
+            &PollPolicy::default(),
         )
         .await?;
 
-    match result {
+    match result.confirmed() {
         Some(receipt) => {
             println!("   交易哈希: {}", receipt.transaction_hash);
             Ok(())
@@ -187,13 +184,11 @@ async fn execute_batch_transactions(
         .poll_until_state(
             &response.transaction_id,
             &[RelayerTransactionState::StateConfirmed],
-            Some(RelayerTransactionState::StateFailed),
-            30,
-            2000,
+            &PollPolicy::default(),
         )
         .await?;
 
-    match result {
+    match result.confirmed() {
         Some(receipt) => {
             println!("   交易哈希: {}", receipt.transaction_hash);
             println!("   状态: {}", receipt.state);
@@ -4,6 +4,7 @@ use crate::types::{
     SafeCreateTransactionArgs, SignatureParams, TransactionRequest, TransactionType,
 };
 use crate::utils::split_and_pack_sig;
+use builder_signing_sdk_rs::AbstractSigner;
 use ethers::abi::{encode, Token};
 use ethers::types::{Address, U256};
 use sha3::{Digest, Keccak256};
@@ -56,24 +57,8 @@ fn safe_create_struct_hash(
     keccak_bytes(&encoded)
 }
 
-pub trait TypedDataSigner: Send + Sync {
-    fn sign_typed_create_proxy(
-        &self,
-        safe_factory: &str,
-        chain_id: u64,
-        payment_token: &str,
-        payment: &str,
-        payment_receiver: &str,
-    ) -> Result<String>;
-}
-
-pub trait AbstractSignerForCreate: Send + Sync {
-    fn get_address(&self) -> Result<String>;
-    fn sign_eip712_digest(&self, digest_hex: &str) -> Result<String>;
-}
-
 pub async fn build_safe_create_transaction_request(
-    signer: &dyn AbstractSignerForCreate,
+    signer: &dyn AbstractSigner,
     safe_factory: &str,
     args: SafeCreateTransactionArgs,
 ) -> Result<TransactionRequest> {
@@ -107,7 +92,7 @@ pub async fn build_safe_create_transaction_request(
     prefix.extend_from_slice(&struct_hash);
     let digest = keccak_bytes(&prefix);
 
-    let sig = signer.sign_eip712_digest(&format!("0x{}", hex::encode(digest)))?;
+    let sig = signer.sign_digest(digest).await?;
     let packed_sig = split_and_pack_sig(&sig);
 
     let sig_params = SignatureParams {
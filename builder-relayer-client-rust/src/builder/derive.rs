@@ -12,7 +12,7 @@ pub fn derive_safe(owner: &str, safe_factory: &str) -> String {
 
     // salt = keccak256(abi.encode(address))
     use ethers::abi::{encode, Token};
-    let salt_bytes = keccak256(&encode(&[Token::Address(owner_addr)]));
+    let salt_bytes = keccak256(encode(&[Token::Address(owner_addr)]));
     let salt = H256::from_slice(&salt_bytes);
     let addr = get_create2_address_from_hash(factory, salt, init_code_hash);
     format!("0x{:x}", addr)
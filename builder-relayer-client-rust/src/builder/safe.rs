@@ -5,6 +5,7 @@ use crate::types::{
     SafeTransaction, SafeTransactionArgs, SignatureParams, TransactionRequest, TransactionType,
 };
 use crate::utils::split_and_pack_sig;
+use builder_signing_sdk_rs::AbstractSigner;
 use ethers::abi::{encode, Token};
 use ethers::types::{Address, U256};
 use sha3::{Digest, Keccak256};
@@ -49,6 +50,7 @@ pub fn eip712_domain_separator(chain_id: U256, verifying_contract: Address) -> [
     keccak(&encoded)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn safe_tx_struct_hash(
     to: Address,
     value: U256,
@@ -86,12 +88,6 @@ fn aggregate_transaction(txns: &[SafeTransaction], safe_multisend: &str) -> Safe
     }
 }
 
-pub trait AbstractSigner: Send + Sync {
-    fn get_address(&self) -> Result<String>;
-    fn sign_message(&self, hash32_hex: &str) -> Result<String>; // legacy message signing
-    fn sign_eip712_digest(&self, digest_hex: &str) -> Result<String>; // explicit typed data digest signing
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SignatureMode {
     /// EIP-191 over structHash (ethers.js signMessage on 32-byte struct hash)
@@ -127,8 +123,11 @@ pub async fn build_safe_transaction_request(
         .clone()
         .unwrap_or_else(|| derive_safe(&args.from, safe_factory));
 
-    eprintln!("[DEBUG] args.safe_address input: {:?}", args.safe_address);
-    eprintln!("[DEBUG] Final safe_address used: {}", safe_address);
+    tracing::debug!(
+        provided = ?args.safe_address,
+        %safe_address,
+        "building safe transaction"
+    );
 
     let to_addr: Address = transaction
         .to
@@ -156,40 +155,25 @@ pub async fn build_safe_transaction_request(
     prefix.extend_from_slice(&struct_hash);
     let digest = keccak(&prefix);
 
-    eprintln!("[DEBUG] Safe address: {}", safe_address);
-    eprintln!(
-        "[DEBUG] Domain separator: 0x{}",
-        hex::encode(domain_separator)
-    );
-    eprintln!("[DEBUG] Struct hash: 0x{}", hex::encode(struct_hash));
-    eprintln!(
-        "[DEBUG] Digest (0x1901||domain||struct): 0x{}",
-        hex::encode(digest)
+    tracing::trace!(
+        domain_separator = %format!("0x{}", hex::encode(domain_separator)),
+        struct_hash = %format!("0x{}", hex::encode(struct_hash)),
+        digest = %format!("0x{}", hex::encode(digest)),
+        "safe tx hashes"
     );
 
     // Signature selection based on mode
     let sig = match sig_mode {
-        SignatureMode::Eip191StructHash => {
-            eprintln!("[DEBUG] SignatureMode=Eip191StructHash (signMessage(structHash))");
-            signer.sign_message(&format!("0x{}", hex::encode(struct_hash)))?
-        }
-        SignatureMode::Eip712Digest => {
-            eprintln!("[DEBUG] SignatureMode=Eip712Digest (sign_eip712_digest(digest))");
-            signer.sign_eip712_digest(&format!("0x{}", hex::encode(digest)))?
-        }
-        SignatureMode::Eip191Digest => {
-            eprintln!("[DEBUG] SignatureMode=Eip191Digest (signMessage(digest))");
-            signer.sign_message(&format!("0x{}", hex::encode(digest)))?
-        }
+        SignatureMode::Eip191StructHash => signer.sign_message(&struct_hash).await?,
+        SignatureMode::Eip712Digest => signer.sign_digest(digest).await?,
+        SignatureMode::Eip191Digest => signer.sign_message(&digest).await?,
     };
-    eprintln!("[DEBUG] Raw signature before pack: {}", sig);
     let packed_sig = split_and_pack_sig(&sig);
-    eprintln!("[DEBUG] Packed signature: {}", packed_sig);
+    tracing::trace!(?sig_mode, raw = %sig, packed = %packed_sig, "safe tx signature");
 
     // Verify signature recovers to correct address using the digest as the
     // signed message (this mirrors the sign_eip712_digest path used above).
-    let signer_addr = signer.get_address()?;
-    eprintln!("[DEBUG] Expected signer address: {}", signer_addr);
+    let signer_addr = signer.address().await?;
 
     use ethers::types::Signature as EthSig;
     if let Ok(sig_parsed) = packed_sig.parse::<EthSig>() {
@@ -207,11 +191,14 @@ pub async fn build_safe_transaction_request(
             }
         };
         if let Ok(recovered) = sig_parsed.recover(verify_hash) {
-            eprintln!("[DEBUG] Recovered address: 0x{:x}", recovered);
             if format!("0x{:x}", recovered).to_lowercase() != signer_addr.to_lowercase() {
-                eprintln!("[WARNING] Signature does not recover to signer address!");
+                tracing::warn!(
+                    expected = %signer_addr,
+                    recovered = %format!("0x{:x}", recovered),
+                    "signature does not recover to signer address"
+                );
             } else {
-                eprintln!("[DEBUG] Signature recovery VERIFIED ✓");
+                tracing::trace!(%signer_addr, "signature recovery verified");
             }
         }
     }
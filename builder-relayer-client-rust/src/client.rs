@@ -1,23 +1,27 @@
-use crate::builder::create::AbstractSignerForCreate;
-use crate::builder::safe::{AbstractSigner, SafeContractConfig, SignatureMode};
+use crate::builder::safe::{SafeContractConfig, SignatureMode};
 use crate::builder::{
     build_safe_create_transaction_request, build_safe_transaction_request, derive_safe,
 };
+use crate::encode::safe::create_safe_multisend_transaction;
 use crate::endpoints::*;
 use crate::errors::{RelayClientError, Result};
+use crate::exchange::{
+    cancel_orders_transaction, increment_nonce_transaction, Exchange, SignedOrderRef,
+};
+use crate::transactions::{trading_approvals, PolymarketContracts, TradingApproval};
+use crate::transport::{HttpRequest, ReqwestTransport, Transport};
 use crate::types::*;
-use crate::utils::sleep_ms;
-use builder_signing_sdk_rs::{BuilderApiKeyCreds, BuilderSigner};
-use reqwest::Client as HttpClient;
+use builder_signing_sdk_rs::{AbstractSigner, BuilderApiKeyCreds, BuilderSigner};
+use std::sync::Arc;
+use tracing::Instrument;
 // use serde_json::json;
 
 pub struct RelayClient {
     pub relayer_url: String,
     pub chain_id: u64,
     pub contract_config: SafeContractConfig,
-    http: HttpClient,
-    signer: Option<Box<dyn AbstractSigner + Send + Sync>>,
-    typed_signer: Option<Box<dyn AbstractSignerForCreate + Send + Sync>>,
+    transport: Arc<dyn Transport>,
+    signer: Option<Arc<dyn AbstractSigner>>,
     builder_signer: Option<BuilderSigner>,
 }
 
@@ -38,20 +42,15 @@ impl RelayClient {
             relayer_url: url.trim_end_matches('/').to_string(),
             chain_id,
             contract_config,
-            http: HttpClient::new(),
+            transport: Arc::new(ReqwestTransport::default()),
             signer: None,
-            typed_signer: None,
             builder_signer: None,
         }
     }
 
-    pub fn with_signer(
-        mut self,
-        signer: Box<dyn AbstractSigner + Send + Sync>,
-        typed: Box<dyn AbstractSignerForCreate + Send + Sync>,
-    ) -> Self {
+    /// Signer for Safe creation and execution; the same `Arc` can be shared with `ClobClient`.
+    pub fn with_signer(mut self, signer: Arc<dyn AbstractSigner>) -> Self {
         self.signer = Some(signer);
-        self.typed_signer = Some(typed);
         self
     }
 
@@ -60,6 +59,18 @@ impl RelayClient {
         self
     }
 
+    /// Replace the HTTP backend (e.g. an in-memory fake relayer in tests).
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Reuse an existing `reqwest::Client` (connection pool, proxy, timeouts).
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.transport = Arc::new(ReqwestTransport::new(client));
+        self
+    }
+
     async fn send<T: for<'de> serde::Deserialize<'de>>(
         &self,
         path: &str,
@@ -68,44 +79,52 @@ impl RelayClient {
         params: Option<Vec<(String, String)>>,
         builder_headers: Option<&std::collections::HashMap<String, String>>,
     ) -> Result<T> {
-        let url = format!("{}{}", self.relayer_url, path);
-        let mut req = match method {
-            "GET" => self.http.get(&url),
-            "POST" => self.http.post(&url),
-            _ => return Err(RelayClientError::Http("unsupported method".into())),
-        };
-        if let Some(p) = params {
-            req = req.query(&p);
-        }
-        if let Some(b) = body {
-            req = req.body(b);
-        }
-        if let Some(h) = builder_headers {
-            for (k, v) in h {
-                req = req.header(k, v);
-            }
+        if method != "GET" && method != "POST" {
+            return Err(RelayClientError::Http("unsupported method".into()));
         }
-        let resp = req
-            .send()
-            .await
-            .map_err(|e| RelayClientError::Http(e.to_string()))?;
-        let status = resp.status();
-        if !status.is_success() {
-            // Try to include response body for diagnostics
-            let text = resp.text().await.unwrap_or_default();
-            let snippet = if text.len() > 512 {
-                &text[..512]
-            } else {
-                &text
+        let request = HttpRequest {
+            method: method.to_string(),
+            url: format!("{}{}", self.relayer_url, path),
+            headers: builder_headers.cloned().unwrap_or_default(),
+            query: params.unwrap_or_default(),
+            body,
+        };
+        let span = tracing::debug_span!(
+            "relayer_request",
+            method,
+            path,
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+        async move {
+            let started = std::time::Instant::now();
+            let outcome = self.transport.send(request).await;
+            let span = tracing::Span::current();
+            span.record("latency_ms", started.elapsed().as_millis() as u64);
+            let resp = match outcome {
+                Ok(resp) => resp,
+                Err(e) => {
+                    tracing::warn!(error = %e, "request failed");
+                    return Err(e);
+                }
             };
-            return Err(RelayClientError::Http(format!(
-                "status {} body: {}",
-                status, snippet
-            )));
+            span.record("status", resp.status);
+            if !resp.is_success() {
+                // Try to include response body for diagnostics
+                let text = &resp.body;
+                let snippet = truncate(text, 512);
+                tracing::warn!(body = %snippet, "non-success response");
+                return Err(RelayClientError::Status {
+                    status: resp.status,
+                    body: snippet.to_string(),
+                });
+            }
+            tracing::debug!("response received");
+            serde_json::from_str::<T>(&resp.body)
+                .map_err(|e| RelayClientError::Serde(e.to_string()))
         }
-        resp.json::<T>()
-            .await
-            .map_err(|e| RelayClientError::Serde(e.to_string()))
+        .instrument(span)
+        .await
     }
 
     pub async fn get_nonce(&self, signer_address: &str, signer_type: &str) -> Result<NoncePayload> {
@@ -175,7 +194,7 @@ impl RelayClient {
     pub async fn deploy(&self) -> Result<RelayerTransactionResponse> {
         self.ensure_signer()?;
         let signer = self.signer.as_ref().unwrap();
-        let addr = signer.get_address()?;
+        let addr = signer.address().await?;
         let safe = derive_safe(&addr, &self.contract_config.safe_factory);
         let deployed: GetDeployedResponse = self
             .send(
@@ -195,11 +214,7 @@ impl RelayClient {
     async fn _deploy(&self) -> Result<RelayerTransactionResponse> {
         self.ensure_signer()?;
         let signer = self.signer.as_ref().unwrap();
-        let typed = self
-            .typed_signer
-            .as_ref()
-            .ok_or(RelayClientError::SignerUnavailable)?;
-        let from = signer.get_address()?;
+        let from = signer.address().await?;
         let args = SafeCreateTransactionArgs {
             from: from.clone(),
             chain_id: self.chain_id,
@@ -208,7 +223,7 @@ impl RelayClient {
             payment_receiver: "0x0000000000000000000000000000000000000000".into(),
         };
         let req = build_safe_create_transaction_request(
-            typed.as_ref(),
+            signer.as_ref(),
             &self.contract_config.safe_factory,
             args,
         )
@@ -228,6 +243,68 @@ impl RelayClient {
         self.execute_with_safe(txns, metadata, None).await
     }
 
+    /// Cancel signed orders on-chain through the Safe (`cancelOrder` / `cancelOrders` on
+    /// `exchange`), for when the CLOB API cannot cancel them. All orders must belong to that
+    /// exchange and have the Safe as maker.
+    pub async fn cancel_orders_onchain(
+        &self,
+        exchange: Exchange,
+        orders: &[SignedOrderRef<'_>],
+    ) -> Result<RelayerTransactionResponse> {
+        let tx = cancel_orders_transaction(exchange.address(self.chain_id)?, orders)?;
+        self.execute(vec![tx], Some("cancel orders".into())).await
+    }
+
+    /// Emergency kill switch: `incrementNonce` on each of `exchanges` (usually `Exchange::ALL`)
+    /// in one Safe transaction, invalidating every order the Safe signed with its current nonce.
+    /// New orders must then be signed with the next nonce.
+    pub async fn increment_exchange_nonce(
+        &self,
+        exchanges: &[Exchange],
+    ) -> Result<RelayerTransactionResponse> {
+        let txns = exchanges
+            .iter()
+            .map(|e| e.address(self.chain_id).map(increment_nonce_transaction))
+            .collect::<Result<Vec<_>>>()?;
+        self.execute(txns, Some("increment nonce".into())).await
+    }
+
+    /// Approve USDC and outcome tokens for the CTF, both exchanges and the neg risk adapter in
+    /// one multisend from the Safe, then wait until the relayer reports it confirmed. Approvals
+    /// in `skip` are known to be in place and left out; returns `None` when nothing is left.
+    /// A failed transaction is returned as `TransactionFailed`, one still pending after the
+    /// default poll timeout as `TransactionTimedOut`.
+    pub async fn ensure_trading_approvals(
+        &self,
+        skip: &[TradingApproval],
+    ) -> Result<Option<RelayerTransaction>> {
+        let contracts = PolymarketContracts::for_chain(self.chain_id)?;
+        let txns = trading_approvals(&contracts, skip);
+        if txns.is_empty() {
+            return Ok(None);
+        }
+        let multisend =
+            create_safe_multisend_transaction(&txns, &self.contract_config.safe_multisend);
+        let resp = self
+            .execute(vec![multisend], Some("trading approvals".into()))
+            .await?;
+        let outcome = self
+            .poll_until_state(
+                &resp.transaction_id,
+                &[RelayerTransactionState::StateConfirmed],
+                &PollPolicy::default(),
+            )
+            .await?;
+        match outcome {
+            PollOutcome::Confirmed(tx) => Ok(Some(tx)),
+            PollOutcome::Failed(tx) => Err(RelayClientError::TransactionFailed(Box::new(tx))),
+            PollOutcome::TimedOut(last) => Err(RelayClientError::TransactionTimedOut {
+                transaction_id: resp.transaction_id,
+                last: last.map(Box::new),
+            }),
+        }
+    }
+
     /// Execute transactions with an optional explicit Safe address
     ///
     /// If `safe_address` is provided, it will be used directly instead of deriving from signer address.
@@ -240,23 +317,11 @@ impl RelayClient {
     ) -> Result<RelayerTransactionResponse> {
         self.ensure_signer()?;
         let signer = self.signer.as_ref().unwrap();
-        let from = signer.get_address()?;
+        let from = signer.address().await?;
 
         // Debug: compare derived safe and provided safe (if any)
         let derived_safe = derive_safe(&from, &self.contract_config.safe_factory);
-        if let Some(ref provided_safe) = safe_address {
-            eprintln!(
-                "[RelayClient][execute] derived_safe={} provided_safe={} equal? {}",
-                derived_safe,
-                provided_safe,
-                (derived_safe.to_lowercase() == provided_safe.to_lowercase())
-            );
-        } else {
-            eprintln!(
-                "[RelayClient][execute] derived_safe={} (no provided safe)",
-                derived_safe
-            );
-        }
+        tracing::debug!(%derived_safe, provided_safe = ?safe_address, "execute");
 
         // Use EOA `from` to fetch nonce, matching the TypeScript SDK behaviour.
         // The relayer expects the nonce query to be done against the signer EOA
@@ -312,7 +377,7 @@ impl RelayClient {
             .await?;
             let body =
                 serde_json::to_string(&req).map_err(|e| RelayClientError::Serde(e.to_string()))?;
-            eprintln!("[RelayClient][execute] outbound body: {}", body);
+            tracing::trace!(attempt, %body, "submitting transaction");
             let res = self.authed_post(SUBMIT_TRANSACTION, &body).await;
             match res {
                 Ok(resp) => return Ok(resp),
@@ -321,7 +386,11 @@ impl RelayClient {
                     let is_invalid_sig =
                         msg.contains("invalid signature") || msg.contains("validation error");
                     if attempt < max_attempts && is_invalid_sig {
-                        eprintln!("[RelayClient][execute] invalid signature, retrying with alternate signature mode...");
+                        tracing::debug!(
+                            attempt,
+                            error = %e,
+                            "invalid signature, retrying with alternate signature mode"
+                        );
                         _last_err = Some(e);
                         continue;
                     } else {
@@ -332,30 +401,294 @@ impl RelayClient {
         }
     }
 
+    /// Poll the transaction until it reaches one of `states`, fails (`STATE_FAILED` /
+    /// `STATE_INVALID`) or `policy.timeout` elapses, backing off between requests. Each request
+    /// is cut off at the deadline; retryable errors (transport, 429, 5xx) count as a missed poll.
     pub async fn poll_until_state(
         &self,
         transaction_id: &str,
         states: &[RelayerTransactionState],
-        fail_state: Option<RelayerTransactionState>,
-        max_polls: usize,
-        poll_freq_ms: u64,
-    ) -> Result<Option<RelayerTransaction>> {
-        let mut count = 0usize;
-        while count < max_polls {
-            let txns = self.get_transaction(transaction_id).await?;
-            if let Some(first) = txns.get(0) {
-                if states.iter().any(|s| first.state == format!("{:?}", s)) {
-                    return Ok(Some(first.clone()));
-                }
-                if let Some(ref fail) = fail_state {
-                    if first.state == format!("{:?}", fail) {
-                        return Ok(None);
+        policy: &PollPolicy,
+    ) -> Result<PollOutcome> {
+        let deadline = tokio::time::Instant::now() + policy.timeout;
+        let mut interval = policy.first_interval();
+        let mut last: Option<RelayerTransaction> = None;
+        loop {
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            match tokio::time::timeout(remaining, self.get_transaction(transaction_id)).await {
+                Ok(Ok(txns)) => {
+                    if let Some(first) = txns.into_iter().next() {
+                        tracing::debug!(transaction_id, state = %first.state, "poll");
+                        if states.contains(&first.state) {
+                            return Ok(PollOutcome::Confirmed(first));
+                        }
+                        if first.state.is_failure() {
+                            return Ok(PollOutcome::Failed(first));
+                        }
+                        last = Some(first);
                     }
                 }
+                Ok(Err(e)) if e.is_retryable() => {
+                    tracing::warn!(transaction_id, error = %e, "poll failed, retrying");
+                }
+                Ok(Err(e)) => return Err(e),
+                Err(_) => return Ok(PollOutcome::TimedOut(last)),
             }
-            count += 1;
-            sleep_ms(poll_freq_ms).await;
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Ok(PollOutcome::TimedOut(last));
+            }
+            tokio::time::sleep(interval.min(deadline - now)).await;
+            interval = policy.next_interval(interval);
         }
-        Ok(None)
+    }
+}
+
+// At most `max` bytes of `text`, cut on a char boundary.
+fn truncate(text: &str, max: usize) -> &str {
+    if text.len() <= max {
+        return text;
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::HttpResponse;
+    use async_trait::async_trait;
+    use builder_signing_sdk_rs::LocalSigner;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    /// In-process relayer: accepts every submission as `tx1` and reports it in `state`.
+    struct FakeRelayer {
+        state: &'static str,
+        seen: Mutex<Vec<HttpRequest>>,
+        // transaction lookups answered with 503 before the real state
+        unavailable: Mutex<usize>,
+    }
+
+    impl FakeRelayer {
+        fn new(state: &'static str) -> Arc<Self> {
+            Self::unavailable_for(state, 0)
+        }
+
+        fn unavailable_for(state: &'static str, polls: usize) -> Arc<Self> {
+            Arc::new(Self {
+                state,
+                seen: Mutex::new(Vec::new()),
+                unavailable: Mutex::new(polls),
+            })
+        }
+
+        fn submitted(&self) -> Vec<serde_json::Value> {
+            self.seen
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| r.url.ends_with(SUBMIT_TRANSACTION))
+                .map(|r| serde_json::from_str(r.body.as_deref().unwrap()).unwrap())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl Transport for FakeRelayer {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            let body = if request.url.ends_with(GET_NONCE) {
+                r#"{"nonce":"0"}"#.to_string()
+            } else if request.url.ends_with(SUBMIT_TRANSACTION) {
+                r#"{"transactionID":"tx1","state":"STATE_NEW"}"#.to_string()
+            } else if request.url.ends_with(GET_TRANSACTION) {
+                let mut unavailable = self.unavailable.lock().unwrap();
+                if *unavailable > 0 {
+                    *unavailable -= 1;
+                    return Ok(HttpResponse {
+                        status: 503,
+                        ..Default::default()
+                    });
+                }
+                serde_json::json!([{
+                    "transaction_id": "tx1",
+                    "transaction_hash": "0xhash",
+                    "from": "", "to": "", "proxy_address": "", "data": "0x",
+                    "nonce": "0", "value": "0", "state": self.state, "type": "SAFE",
+                    "metadata": "", "created_at": "", "updated_at": ""
+                }])
+                .to_string()
+            } else {
+                return Ok(HttpResponse {
+                    status: 404,
+                    ..Default::default()
+                });
+            };
+            self.seen.lock().unwrap().push(request);
+            Ok(HttpResponse {
+                status: 200,
+                headers: Default::default(),
+                body,
+            })
+        }
+    }
+
+    /// Relayer that never answers.
+    struct StalledRelayer;
+
+    #[async_trait]
+    impl Transport for StalledRelayer {
+        async fn send(&self, _request: HttpRequest) -> Result<HttpResponse> {
+            std::future::pending().await
+        }
+    }
+
+    fn client(relayer: Arc<dyn Transport>) -> RelayClient {
+        let signer =
+            LocalSigner::new("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+                .unwrap();
+        RelayClient::new("http://fake", 137)
+            .with_signer(Arc::new(signer))
+            .with_transport(relayer)
+    }
+
+    #[test]
+    fn truncates_on_char_boundary() {
+        assert_eq!(truncate("short", 512), "short");
+        let body = format!("{}é", "a".repeat(511));
+        assert_eq!(truncate(&body, 512), "a".repeat(511));
+        assert_eq!(truncate("ééé", 3), "é");
+    }
+
+    #[tokio::test]
+    async fn ensures_trading_approvals() {
+        let contracts = PolymarketContracts::polygon();
+        let relayer = FakeRelayer::new("STATE_CONFIRMED");
+        let skip = [
+            TradingApproval::CollateralCtf,
+            TradingApproval::OutcomeExchange,
+        ];
+        let tx = client(relayer.clone())
+            .ensure_trading_approvals(&skip)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(tx.state, RelayerTransactionState::StateConfirmed);
+
+        let expected: Vec<SafeTransaction> = [
+            TradingApproval::CollateralExchange,
+            TradingApproval::CollateralNegRiskExchange,
+            TradingApproval::CollateralNegRiskAdapter,
+            TradingApproval::OutcomeNegRiskExchange,
+            TradingApproval::OutcomeNegRiskAdapter,
+        ]
+        .into_iter()
+        .map(|a| a.transaction(&contracts))
+        .collect();
+        let multisend = create_safe_multisend_transaction(
+            &expected,
+            "0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761",
+        );
+        let submitted = relayer.submitted();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0]["data"], multisend.data);
+        assert_eq!(submitted[0]["to"], multisend.to);
+        assert_eq!(submitted[0]["metadata"], "trading approvals");
+
+        // nothing left to approve: no request at all
+        let relayer = FakeRelayer::new("STATE_CONFIRMED");
+        let done = client(relayer.clone())
+            .ensure_trading_approvals(&TradingApproval::ALL)
+            .await
+            .unwrap();
+        assert!(done.is_none());
+        assert!(relayer.seen.lock().unwrap().is_empty());
+
+        let err = client(FakeRelayer::new("STATE_FAILED"))
+            .ensure_trading_approvals(&[])
+            .await
+            .unwrap_err();
+        match err {
+            RelayClientError::TransactionFailed(tx) => {
+                assert_eq!(tx.transaction_id, "tx1");
+                assert_eq!(tx.state, RelayerTransactionState::StateFailed);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn polls_until_state() {
+        let policy = PollPolicy {
+            timeout: Duration::from_millis(250),
+            initial_interval: Duration::ZERO,
+            ..Default::default()
+        };
+        let mined = [RelayerTransactionState::StateMined];
+
+        let outcome = client(FakeRelayer::new("STATE_MINED"))
+            .poll_until_state("tx1", &mined, &policy)
+            .await
+            .unwrap();
+        assert_eq!(
+            outcome.confirmed().unwrap().state,
+            RelayerTransactionState::StateMined
+        );
+
+        match client(FakeRelayer::new("STATE_INVALID"))
+            .poll_until_state("tx1", &mined, &policy)
+            .await
+            .unwrap()
+        {
+            PollOutcome::Failed(tx) => assert_eq!(tx.state, RelayerTransactionState::StateInvalid),
+            other => panic!("expected Failed, got {other:?}"),
+        }
+
+        // a zero initial interval still waits between polls
+        let relayer = FakeRelayer::new("STATE_NEW");
+        match client(relayer.clone())
+            .poll_until_state("tx1", &mined, &policy)
+            .await
+            .unwrap()
+        {
+            PollOutcome::TimedOut(Some(tx)) => {
+                assert_eq!(tx.transaction_id, "tx1");
+                assert_eq!(tx.state, RelayerTransactionState::StateNew);
+            }
+            other => panic!("expected TimedOut, got {other:?}"),
+        }
+        assert!(relayer.seen.lock().unwrap().len() <= 4);
+    }
+
+    #[tokio::test]
+    async fn polling_survives_transient_errors_and_stalls() {
+        let confirmed = [RelayerTransactionState::StateConfirmed];
+        let policy = PollPolicy {
+            initial_interval: Duration::ZERO,
+            ..PollPolicy::timeout_after(Duration::from_secs(5))
+        };
+        let relayer = FakeRelayer::unavailable_for("STATE_CONFIRMED", 1);
+        let outcome = client(relayer.clone())
+            .poll_until_state("tx1", &confirmed, &policy)
+            .await
+            .unwrap();
+        assert!(outcome.confirmed().is_some());
+        assert_eq!(*relayer.unavailable.lock().unwrap(), 0);
+
+        // a request that never returns is cut off at the deadline
+        let started = std::time::Instant::now();
+        let outcome = client(Arc::new(StalledRelayer))
+            .poll_until_state(
+                "tx1",
+                &confirmed,
+                &PollPolicy::timeout_after(Duration::from_millis(200)),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(outcome, PollOutcome::TimedOut(None)));
+        assert!(started.elapsed() < Duration::from_secs(2));
     }
 }
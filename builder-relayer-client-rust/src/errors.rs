@@ -1,3 +1,4 @@
+use crate::types::RelayerTransaction;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -10,10 +11,42 @@ pub enum RelayClientError {
     SafeNotDeployed,
     #[error("http error: {0}")]
     Http(String),
+    /// The request did not reach the relayer or its response could not be read.
+    #[error("transport error: {0}")]
+    Transport(String),
+    /// Non-2xx response.
+    #[error("http error: status {status} body: {body}")]
+    Status { status: u16, body: String },
     #[error("serialization error: {0}")]
     Serde(String),
     #[error("invalid network")]
     InvalidNetwork,
+    #[error("signer error: {0}")]
+    Signer(#[from] builder_signing_sdk_rs::SignerError),
+    /// The relayer reported `STATE_FAILED` or `STATE_INVALID`.
+    #[error("transaction {} ended in {}", .0.transaction_id, .0.state)]
+    TransactionFailed(Box<RelayerTransaction>),
+    #[error("transaction {transaction_id} was not confirmed in time")]
+    TransactionTimedOut {
+        transaction_id: String,
+        /// Last state seen, `None` if the relayer never returned the transaction.
+        last: Option<Box<RelayerTransaction>>,
+    },
+    #[error("calldata error: {0}")]
+    Calldata(#[from] order_utils_rs::CalldataError),
+    #[error("no orders to cancel")]
+    NoOrders,
+}
+
+impl RelayClientError {
+    /// Transport failures, 429 and 5xx: the same request may succeed later.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            RelayClientError::Transport(_) => true,
+            RelayClientError::Status { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, RelayClientError>;
@@ -0,0 +1,115 @@
+//! Exchange transactions sent through the Safe: on-chain order cancellation and nonce bumps,
+//! the fallback when the CLOB API cannot cancel orders.
+//!
+//! The Safe is `msg.sender`, so these only affect orders whose maker is the Safe
+//! (signature type `POLY_GNOSIS_SAFE`).
+
+use crate::errors::{RelayClientError, Result};
+use crate::transactions::PolymarketContracts;
+use crate::types::{OperationType, SafeTransaction};
+use ethers::types::Address;
+use order_utils_rs::{cancel_order_calldata, cancel_orders_calldata, increment_nonce_calldata};
+
+pub use order_utils_rs::{Order, SignedOrderRef};
+
+/// CTF Exchange (binary markets) or NegRisk CTF Exchange (neg risk markets).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exchange {
+    Ctf,
+    NegRisk,
+}
+
+impl Exchange {
+    pub const ALL: [Exchange; 2] = [Exchange::Ctf, Exchange::NegRisk];
+
+    /// Contract address on `chain_id` (Polygon 137, Amoy 80002).
+    pub fn address(self, chain_id: u64) -> Result<Address> {
+        let contracts = PolymarketContracts::for_chain(chain_id)?;
+        Ok(match self {
+            Exchange::Ctf => contracts.exchange,
+            Exchange::NegRisk => contracts.neg_risk_exchange,
+        })
+    }
+}
+
+fn exchange_call(exchange: Address, data: String) -> SafeTransaction {
+    SafeTransaction {
+        to: format!("{:#x}", exchange),
+        operation: OperationType::Call,
+        data,
+        value: "0".to_string(),
+    }
+}
+
+/// `cancelOrder` for one order, `cancelOrders` for several; no orders is an error rather than
+/// a relayed no-op.
+pub fn cancel_orders_transaction(
+    exchange: Address,
+    orders: &[SignedOrderRef],
+) -> Result<SafeTransaction> {
+    let data = match orders {
+        [] => return Err(RelayClientError::NoOrders),
+        [(order, signature)] => cancel_order_calldata(order, signature)?,
+        _ => cancel_orders_calldata(orders)?,
+    };
+    Ok(exchange_call(exchange, data))
+}
+
+/// `incrementNonce`: every order signed with the current nonce becomes invalid.
+pub fn increment_nonce_transaction(exchange: Address) -> SafeTransaction {
+    exchange_call(exchange, increment_nonce_calldata())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::hex_to_address;
+
+    #[test]
+    fn builds_exchange_transactions() {
+        let ctf = hex_to_address("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+        let neg_risk = hex_to_address("0xC5d563A36AE78145C45a50134d48A1215220f80a");
+        assert_eq!(Exchange::Ctf.address(137).unwrap(), ctf);
+        assert_eq!(Exchange::NegRisk.address(137).unwrap(), neg_risk);
+        assert_eq!(
+            Exchange::Ctf.address(80002).unwrap(),
+            hex_to_address("0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40")
+        );
+        assert_eq!(Exchange::NegRisk.address(80002).unwrap(), neg_risk);
+        assert!(matches!(
+            Exchange::Ctf.address(1),
+            Err(RelayClientError::InvalidNetwork)
+        ));
+
+        let order = Order {
+            salt: "1".into(),
+            maker: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".into(),
+            signer: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".into(),
+            taker: order_utils_rs::ZERO_ADDRESS.into(),
+            token_id: "1234".into(),
+            maker_amount: "5050000".into(),
+            taker_amount: "10000000".into(),
+            expiration: "0".into(),
+            nonce: "0".into(),
+            fee_rate_bps: "0".into(),
+            side: 0,
+            signature_type: 2,
+        };
+        let sig = format!("0x{}1b", "ab".repeat(64));
+
+        let one = cancel_orders_transaction(ctf, &[(&order, &sig)]).unwrap();
+        assert_eq!(&one.data[..10], "0xa6dfcf86"); // cancelOrder
+        assert_eq!(one.to, format!("{:#x}", ctf));
+        assert_eq!(one.value, "0");
+        assert!(matches!(one.operation, OperationType::Call));
+        let many = cancel_orders_transaction(neg_risk, &[(&order, &sig), (&order, &sig)]).unwrap();
+        assert_eq!(&many.data[..10], "0xfa950b48"); // cancelOrders
+        assert_eq!(many.to, format!("{:#x}", neg_risk));
+        assert!(matches!(
+            cancel_orders_transaction(ctf, &[]),
+            Err(RelayClientError::NoOrders)
+        ));
+
+        assert_eq!(increment_nonce_transaction(ctf).data, "0x627cdcb9");
+    }
+}
@@ -3,7 +3,10 @@ pub mod client;
 pub mod encode;
 pub mod endpoints;
 pub mod errors;
+pub mod exchange;
 pub mod signer;
+pub mod transactions;
+pub mod transport;
 pub mod types;
 pub mod utils; // added DummySigner
 
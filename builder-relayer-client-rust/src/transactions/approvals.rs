@@ -0,0 +1,59 @@
+//! Allowances a Safe needs before trading: USDC for the CTF (split / merge), both exchanges and
+//! the neg risk adapter, and outcome tokens (`setApprovalForAll`) for the exchanges and adapter.
+
+use super::{erc1155, erc20, PolymarketContracts};
+use crate::types::SafeTransaction;
+use ethers::types::U256;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TradingApproval {
+    /// USDC allowance for the CTF (split / merge).
+    CollateralCtf,
+    CollateralExchange,
+    CollateralNegRiskExchange,
+    CollateralNegRiskAdapter,
+    /// Outcome tokens (`setApprovalForAll`) for the CTF exchange.
+    OutcomeExchange,
+    OutcomeNegRiskExchange,
+    OutcomeNegRiskAdapter,
+}
+
+impl TradingApproval {
+    pub const ALL: [TradingApproval; 7] = [
+        TradingApproval::CollateralCtf,
+        TradingApproval::CollateralExchange,
+        TradingApproval::CollateralNegRiskExchange,
+        TradingApproval::CollateralNegRiskAdapter,
+        TradingApproval::OutcomeExchange,
+        TradingApproval::OutcomeNegRiskExchange,
+        TradingApproval::OutcomeNegRiskAdapter,
+    ];
+
+    /// Unlimited USDC allowance or `setApprovalForAll(spender, true)`.
+    pub fn transaction(self, contracts: &PolymarketContracts) -> SafeTransaction {
+        let usdc = |spender| erc20::approve(contracts.collateral, spender, U256::MAX);
+        let outcome =
+            |operator| erc1155::set_approval_for_all(contracts.conditional_tokens, operator, true);
+        match self {
+            TradingApproval::CollateralCtf => usdc(contracts.conditional_tokens),
+            TradingApproval::CollateralExchange => usdc(contracts.exchange),
+            TradingApproval::CollateralNegRiskExchange => usdc(contracts.neg_risk_exchange),
+            TradingApproval::CollateralNegRiskAdapter => usdc(contracts.neg_risk_adapter),
+            TradingApproval::OutcomeExchange => outcome(contracts.exchange),
+            TradingApproval::OutcomeNegRiskExchange => outcome(contracts.neg_risk_exchange),
+            TradingApproval::OutcomeNegRiskAdapter => outcome(contracts.neg_risk_adapter),
+        }
+    }
+}
+
+/// Approval transactions of `TradingApproval::ALL` except the ones in `skip`.
+pub fn trading_approvals(
+    contracts: &PolymarketContracts,
+    skip: &[TradingApproval],
+) -> Vec<SafeTransaction> {
+    TradingApproval::ALL
+        .into_iter()
+        .filter(|a| !skip.contains(a))
+        .map(|a| a.transaction(contracts))
+        .collect()
+}
@@ -0,0 +1,78 @@
+//! Conditional Tokens Framework calls with the Polymarket collateral and an empty parent
+//! collection (top-level positions).
+
+use super::{contract_call, PolymarketContracts};
+use crate::types::SafeTransaction;
+use ethers::abi::Token;
+use ethers::types::{H256, U256};
+
+/// Index sets of the two outcomes of a binary market.
+pub const BINARY_PARTITION: [u64; 2] = [1, 2];
+
+fn uints(values: &[u64]) -> Token {
+    Token::Array(values.iter().map(|v| Token::Uint(U256::from(*v))).collect())
+}
+
+fn position_call(
+    contracts: &PolymarketContracts,
+    signature: &str,
+    condition_id: H256,
+    partition: &[u64],
+    amount: Option<U256>,
+) -> SafeTransaction {
+    let mut args = vec![
+        Token::Address(contracts.collateral),
+        Token::FixedBytes(H256::zero().as_bytes().to_vec()),
+        Token::FixedBytes(condition_id.as_bytes().to_vec()),
+        uints(partition),
+    ];
+    args.extend(amount.map(Token::Uint));
+    contract_call(contracts.conditional_tokens, signature, &args)
+}
+
+/// `splitPosition`: lock `amount` collateral and mint that many of each outcome token.
+pub fn split_position(
+    contracts: &PolymarketContracts,
+    condition_id: H256,
+    partition: &[u64],
+    amount: U256,
+) -> SafeTransaction {
+    position_call(
+        contracts,
+        "splitPosition(address,bytes32,bytes32,uint256[],uint256)",
+        condition_id,
+        partition,
+        Some(amount),
+    )
+}
+
+/// `mergePositions`: burn `amount` of each outcome token and get the collateral back.
+pub fn merge_positions(
+    contracts: &PolymarketContracts,
+    condition_id: H256,
+    partition: &[u64],
+    amount: U256,
+) -> SafeTransaction {
+    position_call(
+        contracts,
+        "mergePositions(address,bytes32,bytes32,uint256[],uint256)",
+        condition_id,
+        partition,
+        Some(amount),
+    )
+}
+
+/// `redeemPositions`: after resolution, burn the whole balance of `index_sets` for collateral.
+pub fn redeem_positions(
+    contracts: &PolymarketContracts,
+    condition_id: H256,
+    index_sets: &[u64],
+) -> SafeTransaction {
+    position_call(
+        contracts,
+        "redeemPositions(address,bytes32,bytes32,uint256[])",
+        condition_id,
+        index_sets,
+        None,
+    )
+}
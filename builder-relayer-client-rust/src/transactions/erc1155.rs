@@ -0,0 +1,34 @@
+use super::contract_call;
+use crate::types::SafeTransaction;
+use ethers::abi::Token;
+use ethers::types::{Address, U256};
+
+/// `setApprovalForAll(operator, approved)`, e.g. outcome tokens for the exchanges.
+pub fn set_approval_for_all(token: Address, operator: Address, approved: bool) -> SafeTransaction {
+    contract_call(
+        token,
+        "setApprovalForAll(address,bool)",
+        &[Token::Address(operator), Token::Bool(approved)],
+    )
+}
+
+/// `safeTransferFrom(from, to, id, amount, "")`; `from` is the Safe for its own tokens.
+pub fn safe_transfer_from(
+    token: Address,
+    from: Address,
+    to: Address,
+    id: U256,
+    amount: U256,
+) -> SafeTransaction {
+    contract_call(
+        token,
+        "safeTransferFrom(address,address,uint256,uint256,bytes)",
+        &[
+            Token::Address(from),
+            Token::Address(to),
+            Token::Uint(id),
+            Token::Uint(amount),
+            Token::Bytes(Vec::new()),
+        ],
+    )
+}
@@ -0,0 +1,22 @@
+use super::contract_call;
+use crate::types::SafeTransaction;
+use ethers::abi::Token;
+use ethers::types::{Address, U256};
+
+/// `approve(spender, amount)`; use `U256::MAX` for an unlimited allowance.
+pub fn approve(token: Address, spender: Address, amount: U256) -> SafeTransaction {
+    contract_call(
+        token,
+        "approve(address,uint256)",
+        &[Token::Address(spender), Token::Uint(amount)],
+    )
+}
+
+/// `transfer(to, amount)`, amount in token base units (6 decimals for USDC).
+pub fn transfer(token: Address, to: Address, amount: U256) -> SafeTransaction {
+    contract_call(
+        token,
+        "transfer(address,uint256)",
+        &[Token::Address(to), Token::Uint(amount)],
+    )
+}
@@ -0,0 +1,221 @@
+//! Typed builders for the Safe transactions used around trading: CTF split / merge / redeem,
+//! neg risk adapter conversions, ERC20 and ERC1155 approvals and transfers.
+//!
+//! Every builder returns a `SafeTransaction` (plain `Call`, no value) for `RelayClient::execute`.
+
+pub mod approvals;
+pub mod ctf;
+pub mod erc1155;
+pub mod erc20;
+pub mod neg_risk;
+
+pub use approvals::{trading_approvals, TradingApproval};
+
+use crate::errors::{RelayClientError, Result};
+use crate::types::{OperationType, SafeTransaction};
+use crate::utils::{hex_to_address, keccak256};
+use ethers::abi::{encode, Token};
+use ethers::types::Address;
+
+/// Polymarket contracts of one chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolymarketContracts {
+    /// Collateral token (USDC.e on Polygon).
+    pub collateral: Address,
+    /// Conditional Tokens Framework (ERC1155 outcome tokens).
+    pub conditional_tokens: Address,
+    pub exchange: Address,
+    pub neg_risk_exchange: Address,
+    pub neg_risk_adapter: Address,
+}
+
+impl PolymarketContracts {
+    pub fn polygon() -> Self {
+        Self {
+            collateral: hex_to_address("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"),
+            conditional_tokens: hex_to_address("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045"),
+            exchange: hex_to_address("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
+            neg_risk_exchange: hex_to_address("0xC5d563A36AE78145C45a50134d48A1215220f80a"),
+            neg_risk_adapter: hex_to_address("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296"),
+        }
+    }
+
+    pub fn amoy() -> Self {
+        Self {
+            collateral: hex_to_address("0x9c4e1703476e875070ee25b56a58b008cfb8fa78"),
+            conditional_tokens: hex_to_address("0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB"),
+            exchange: hex_to_address("0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"),
+            neg_risk_exchange: hex_to_address("0xC5d563A36AE78145C45a50134d48A1215220f80a"),
+            neg_risk_adapter: hex_to_address("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296"),
+        }
+    }
+
+    /// Polygon (137) or Amoy (80002).
+    pub fn for_chain(chain_id: u64) -> Result<Self> {
+        match chain_id {
+            137 => Ok(Self::polygon()),
+            80002 => Ok(Self::amoy()),
+            _ => Err(RelayClientError::InvalidNetwork),
+        }
+    }
+}
+
+/// `signature` is the canonical function signature, e.g. `approve(address,uint256)`.
+pub(crate) fn contract_call(to: Address, signature: &str, args: &[Token]) -> SafeTransaction {
+    let mut data = keccak256(signature.as_bytes())[..4].to_vec();
+    data.extend(encode(args));
+    SafeTransaction {
+        to: format!("{:#x}", to),
+        operation: OperationType::Call,
+        data: format!("0x{}", hex::encode(data)),
+        value: "0".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::abi::AbiParser;
+    use ethers::types::{H256, U256};
+
+    // Check the selector against the human-readable ABI and decode the arguments back.
+    fn decode(
+        tx: &SafeTransaction,
+        to: Address,
+        function: &str,
+        selector: Option<&str>,
+    ) -> Vec<Token> {
+        let f = AbiParser::default().parse_function(function).unwrap();
+        let data = hex::decode(&tx.data[2..]).unwrap();
+        assert_eq!(data[..4], f.short_signature(), "{}", function);
+        if let Some(selector) = selector {
+            assert_eq!(&tx.data[..10], selector, "{}", function);
+        }
+        assert_eq!(tx.to, format!("{:#x}", to));
+        assert_eq!(tx.value, "0");
+        f.decode_input(&data[4..]).unwrap()
+    }
+
+    #[test]
+    fn encodes_contract_calls() {
+        let c = PolymarketContracts::polygon();
+        let condition = H256::repeat_byte(0x11);
+        let amount = U256::from(1_000_000u64);
+        let bytes32 = |h: H256| Token::FixedBytes(h.as_bytes().to_vec());
+        let uints = |v: &[u64]| Token::Array(v.iter().map(|n| Token::Uint((*n).into())).collect());
+        let position = |partition: &[u64]| {
+            vec![
+                Token::Address(c.collateral),
+                bytes32(H256::zero()),
+                bytes32(condition),
+                uints(partition),
+            ]
+        };
+
+        let mut split = position(&ctf::BINARY_PARTITION);
+        split.push(Token::Uint(amount));
+        assert_eq!(
+            decode(
+                &ctf::split_position(&c, condition, &ctf::BINARY_PARTITION, amount),
+                c.conditional_tokens,
+                "function splitPosition(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] partition, uint256 amount)",
+                Some("0x72ce4275"),
+            ),
+            split
+        );
+        assert_eq!(
+            decode(
+                &ctf::merge_positions(&c, condition, &ctf::BINARY_PARTITION, amount),
+                c.conditional_tokens,
+                "function mergePositions(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] partition, uint256 amount)",
+                Some("0x9e7212ad"),
+            ),
+            split
+        );
+        assert_eq!(
+            decode(
+                &ctf::redeem_positions(&c, condition, &[2]),
+                c.conditional_tokens,
+                "function redeemPositions(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] indexSets)",
+                Some("0x01b7037c"),
+            ),
+            position(&[2])
+        );
+
+        // adapter selectors come from the ABI only
+        assert_eq!(
+            decode(
+                &neg_risk::convert_positions(&c, condition, U256::from(5), amount),
+                c.neg_risk_adapter,
+                "function convertPositions(bytes32 marketId, uint256 indexSet, uint256 amount)",
+                None,
+            ),
+            vec![
+                bytes32(condition),
+                Token::Uint(5.into()),
+                Token::Uint(amount)
+            ]
+        );
+        assert_eq!(
+            decode(
+                &neg_risk::redeem_positions(&c, condition, &[amount, U256::zero()]),
+                c.neg_risk_adapter,
+                "function redeemPositions(bytes32 conditionId, uint256[] amounts)",
+                None,
+            ),
+            vec![
+                bytes32(condition),
+                Token::Array(vec![Token::Uint(amount), Token::Uint(U256::zero())])
+            ]
+        );
+
+        assert_eq!(
+            decode(
+                &erc20::approve(c.collateral, c.exchange, U256::MAX),
+                c.collateral,
+                "function approve(address spender, uint256 amount)",
+                Some("0x095ea7b3"),
+            ),
+            vec![Token::Address(c.exchange), Token::Uint(U256::MAX)]
+        );
+        assert_eq!(
+            decode(
+                &erc20::transfer(c.collateral, c.exchange, amount),
+                c.collateral,
+                "function transfer(address to, uint256 amount)",
+                Some("0xa9059cbb"),
+            ),
+            vec![Token::Address(c.exchange), Token::Uint(amount)]
+        );
+        assert_eq!(
+            decode(
+                &erc1155::set_approval_for_all(c.conditional_tokens, c.neg_risk_adapter, true),
+                c.conditional_tokens,
+                "function setApprovalForAll(address operator, bool approved)",
+                Some("0xa22cb465"),
+            ),
+            vec![Token::Address(c.neg_risk_adapter), Token::Bool(true)]
+        );
+        assert_eq!(
+            decode(
+                &erc1155::safe_transfer_from(
+                    c.conditional_tokens,
+                    c.exchange,
+                    c.collateral,
+                    U256::from(7),
+                    amount,
+                ),
+                c.conditional_tokens,
+                "function safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes data)",
+                Some("0xf242432a"),
+            ),
+            vec![
+                Token::Address(c.exchange),
+                Token::Address(c.collateral),
+                Token::Uint(7.into()),
+                Token::Uint(amount),
+                Token::Bytes(Vec::new()),
+            ]
+        );
+    }
+}
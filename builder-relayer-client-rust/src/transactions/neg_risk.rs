@@ -0,0 +1,41 @@
+//! Neg risk adapter calls (multi-outcome markets).
+
+use super::{contract_call, PolymarketContracts};
+use crate::types::SafeTransaction;
+use ethers::abi::Token;
+use ethers::types::{H256, U256};
+
+/// `convertPositions(marketId, indexSet, amount)`: convert `amount` NO tokens of the questions
+/// in `index_set` (bit i = question i) into YES tokens of the other questions plus collateral.
+pub fn convert_positions(
+    contracts: &PolymarketContracts,
+    market_id: H256,
+    index_set: U256,
+    amount: U256,
+) -> SafeTransaction {
+    contract_call(
+        contracts.neg_risk_adapter,
+        "convertPositions(bytes32,uint256,uint256)",
+        &[
+            Token::FixedBytes(market_id.as_bytes().to_vec()),
+            Token::Uint(index_set),
+            Token::Uint(amount),
+        ],
+    )
+}
+
+/// `redeemPositions(conditionId, amounts)`: redeem `[yes, no]` amounts of a resolved question.
+pub fn redeem_positions(
+    contracts: &PolymarketContracts,
+    condition_id: H256,
+    amounts: &[U256],
+) -> SafeTransaction {
+    contract_call(
+        contracts.neg_risk_adapter,
+        "redeemPositions(bytes32,uint256[])",
+        &[
+            Token::FixedBytes(condition_id.as_bytes().to_vec()),
+            Token::Array(amounts.iter().map(|a| Token::Uint(*a)).collect()),
+        ],
+    )
+}
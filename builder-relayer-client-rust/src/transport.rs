@@ -0,0 +1,93 @@
+use crate::errors::{RelayClientError, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// A fully prepared relayer request (final headers, serialized body).
+#[derive(Debug, Clone, Default)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub query: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+/// Raw relayer response: status, lower-cased headers and body text.
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(|v| v.as_str())
+    }
+}
+
+/// Pluggable HTTP backend for `RelayClient`; swap in a fake relayer for tests.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+/// Default transport backed by `reqwest::Client`.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes())
+            .map_err(|_| RelayClientError::Http("unsupported method".into()))?;
+        let mut req = self.client.request(method, &request.url);
+        for (k, v) in request.headers.iter() {
+            req = req.header(k, v);
+        }
+        if !request.query.is_empty() {
+            req = req.query(&request.query);
+        }
+        if let Some(body) = request.body {
+            req = req.body(body);
+        }
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| RelayClientError::Transport(e.to_string()))?;
+        let status = resp.status().as_u16();
+        let mut headers = HashMap::new();
+        for (k, v) in resp.headers().iter() {
+            if let Ok(s) = v.to_str() {
+                headers.insert(k.as_str().to_ascii_lowercase(), s.to_string());
+            }
+        }
+        let body = resp
+            .text()
+            .await
+            .map_err(|e| RelayClientError::Transport(e.to_string()))?;
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
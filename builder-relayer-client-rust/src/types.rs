@@ -90,21 +90,165 @@ pub struct SafeCreateTransactionArgs {
     pub payment_receiver: String,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// Relayer transaction state (`STATE_MINED`, ... on the wire); states added by newer relayer
+/// versions are kept as `Unknown`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum RelayerTransactionState {
-    #[serde(rename = "STATE_NEW")]
     StateNew,
-    #[serde(rename = "STATE_EXECUTED")]
     StateExecuted,
-    #[serde(rename = "STATE_MINED")]
     StateMined,
-    #[serde(rename = "STATE_INVALID")]
     StateInvalid,
-    #[serde(rename = "STATE_CONFIRMED")]
     StateConfirmed,
-    #[serde(rename = "STATE_FAILED")]
     StateFailed,
+    Unknown(String),
+}
+
+impl RelayerTransactionState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RelayerTransactionState::StateNew => "STATE_NEW",
+            RelayerTransactionState::StateExecuted => "STATE_EXECUTED",
+            RelayerTransactionState::StateMined => "STATE_MINED",
+            RelayerTransactionState::StateInvalid => "STATE_INVALID",
+            RelayerTransactionState::StateConfirmed => "STATE_CONFIRMED",
+            RelayerTransactionState::StateFailed => "STATE_FAILED",
+            RelayerTransactionState::Unknown(s) => s,
+        }
+    }
+
+    /// `STATE_FAILED` or `STATE_INVALID`: the transaction will not be mined.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            RelayerTransactionState::StateFailed | RelayerTransactionState::StateInvalid
+        )
+    }
+}
+
+impl Default for RelayerTransactionState {
+    fn default() -> Self {
+        RelayerTransactionState::Unknown(String::new())
+    }
+}
+
+impl From<&str> for RelayerTransactionState {
+    fn from(s: &str) -> Self {
+        match s {
+            "STATE_NEW" => RelayerTransactionState::StateNew,
+            "STATE_EXECUTED" => RelayerTransactionState::StateExecuted,
+            "STATE_MINED" => RelayerTransactionState::StateMined,
+            "STATE_INVALID" => RelayerTransactionState::StateInvalid,
+            "STATE_CONFIRMED" => RelayerTransactionState::StateConfirmed,
+            "STATE_FAILED" => RelayerTransactionState::StateFailed,
+            other => RelayerTransactionState::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for RelayerTransactionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for RelayerTransactionState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RelayerTransactionState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(RelayerTransactionState::from(s.as_str()))
+    }
+}
+
+/// How `RelayClient::poll_until_state` waits: the interval starts at `initial_interval` and
+/// grows by `multiplier` up to `max_interval` until `timeout` has elapsed.
+#[derive(Clone, Debug)]
+pub struct PollPolicy {
+    pub timeout: std::time::Duration,
+    pub initial_interval: std::time::Duration,
+    pub max_interval: std::time::Duration,
+    pub multiplier: f64,
+}
+
+impl Default for PollPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: std::time::Duration::from_secs(120),
+            initial_interval: std::time::Duration::from_secs(1),
+            max_interval: std::time::Duration::from_secs(10),
+            multiplier: 1.5,
+        }
+    }
+}
+
+impl PollPolicy {
+    /// Shortest wait between two polls, whatever `initial_interval` says.
+    pub const MIN_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+    /// Default backoff with another overall timeout.
+    pub fn timeout_after(timeout: std::time::Duration) -> Self {
+        Self {
+            timeout,
+            ..Default::default()
+        }
+    }
+
+    /// First wait, at least `MIN_INTERVAL`.
+    pub(crate) fn first_interval(&self) -> std::time::Duration {
+        self.initial_interval.max(Self::MIN_INTERVAL)
+    }
+
+    /// Wait after `interval`: scaled by `multiplier` (a non-finite one or one below 1 keeps
+    /// the interval) and capped at `max_interval`.
+    pub(crate) fn next_interval(&self, interval: std::time::Duration) -> std::time::Duration {
+        let multiplier = if self.multiplier.is_finite() {
+            self.multiplier.max(1.0)
+        } else {
+            1.0
+        };
+        let max = self.max_interval.max(Self::MIN_INTERVAL);
+        std::time::Duration::try_from_secs_f64(interval.as_secs_f64() * multiplier)
+            .unwrap_or(max)
+            .min(max)
+    }
+}
+
+/// Result of `RelayClient::poll_until_state`.
+#[derive(Clone, Debug)]
+pub enum PollOutcome {
+    /// Reached one of the requested states.
+    Confirmed(RelayerTransaction),
+    /// `STATE_FAILED` or `STATE_INVALID`.
+    Failed(RelayerTransaction),
+    /// Timeout elapsed; last state seen, `None` if the relayer never returned the transaction.
+    TimedOut(Option<RelayerTransaction>),
+}
+
+impl PollOutcome {
+    /// The transaction when confirmed.
+    pub fn confirmed(self) -> Option<RelayerTransaction> {
+        match self {
+            PollOutcome::Confirmed(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Last known transaction, whatever the outcome.
+    pub fn transaction(&self) -> Option<&RelayerTransaction> {
+        match self {
+            PollOutcome::Confirmed(tx) | PollOutcome::Failed(tx) => Some(tx),
+            PollOutcome::TimedOut(tx) => tx.as_ref(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -117,7 +261,7 @@ pub struct RelayerTransaction {
     pub data: String,
     pub nonce: String,
     pub value: String,
-    pub state: String,
+    pub state: RelayerTransactionState,
     pub r#type: String,
     pub metadata: String,
     pub created_at: String,
@@ -137,7 +281,7 @@ pub struct RelayerTransactionResponse {
     pub transaction_id: String,
 
     #[serde(default, alias = "status", alias = "state")]
-    pub state: String,
+    pub state: RelayerTransactionState,
 
     // Some responses use `hash` or `txHash`; keep both `hash` and `transaction_hash`
     #[serde(default, alias = "txHash", alias = "hash")]
@@ -151,3 +295,63 @@ pub struct RelayerTransactionResponse {
 pub struct GetDeployedResponse {
     pub deployed: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn transaction_state_serde() {
+        let state: RelayerTransactionState = serde_json::from_str(r#""STATE_MINED""#).unwrap();
+        assert_eq!(state, RelayerTransactionState::StateMined);
+
+        let state: RelayerTransactionState = serde_json::from_str(r#""STATE_QUEUED""#).unwrap();
+        assert_eq!(
+            state,
+            RelayerTransactionState::Unknown("STATE_QUEUED".to_string())
+        );
+        assert_eq!(serde_json::to_string(&state).unwrap(), r#""STATE_QUEUED""#);
+
+        assert!(RelayerTransactionState::from("STATE_FAILED").is_failure());
+        assert!(RelayerTransactionState::from("STATE_INVALID").is_failure());
+        assert!(!RelayerTransactionState::StateMined.is_failure());
+        assert!(!state.is_failure());
+    }
+
+    #[test]
+    fn poll_intervals() {
+        let policy = PollPolicy {
+            initial_interval: Duration::ZERO,
+            ..Default::default()
+        };
+        assert_eq!(policy.first_interval(), PollPolicy::MIN_INTERVAL);
+        assert_eq!(
+            policy.next_interval(Duration::from_secs(2)),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            policy.next_interval(Duration::from_secs(8)),
+            policy.max_interval
+        );
+
+        for multiplier in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 0.5] {
+            let policy = PollPolicy {
+                multiplier,
+                ..Default::default()
+            };
+            assert_eq!(
+                policy.next_interval(Duration::from_secs(2)),
+                Duration::from_secs(2)
+            );
+        }
+        let policy = PollPolicy {
+            multiplier: f64::MAX,
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.next_interval(Duration::from_secs(2)),
+            policy.max_interval
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeystoreError {
+    #[error("keystore io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Wrong password, corrupted file or unsupported KDF.
+    #[error("keystore decryption failed: {0}")]
+    Decrypt(String),
+    #[error("keystore contents: {0}")]
+    Serde(#[from] serde_json::Error),
+}
+
+/// Password-encrypted JSON file in the Ethereum keystore v3 layout (scrypt + AES-128-CTR,
+/// pbkdf2 accepted on read), holding any serializable value instead of a private key.
+#[derive(Clone)]
+pub struct EncryptedFile {
+    path: PathBuf,
+    password: String,
+}
+
+impl EncryptedFile {
+    pub fn new(path: impl Into<PathBuf>, password: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            password: password.into(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    /// Encrypt `value` and replace the file (owner read/write only on unix).
+    pub fn save<T: Serialize>(&self, value: &T) -> Result<(), KeystoreError> {
+        let plain = serde_json::to_vec(value)?;
+        let dir = match self.path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        std::fs::create_dir_all(&dir)?;
+        let name = self
+            .path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| KeystoreError::Decrypt(format!("invalid path {:?}", self.path)))?;
+        // write next to the target, then rename, so a crash never leaves a truncated file
+        let tmp = format!(".{}.tmp", name);
+        eth_keystore::encrypt_key(
+            &dir,
+            &mut rand::thread_rng(),
+            plain,
+            &self.password,
+            Some(&tmp),
+        )
+        .map_err(|e| KeystoreError::Decrypt(e.to_string()))?;
+        let tmp = dir.join(tmp);
+        restrict_permissions(&tmp)?;
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    pub fn load<T: DeserializeOwned>(&self) -> Result<T, KeystoreError> {
+        if !self.exists() {
+            return Err(KeystoreError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("{} not found", self.path.display()),
+            )));
+        }
+        let plain = eth_keystore::decrypt_key(&self.path, &self.password)
+            .map_err(|e| KeystoreError::Decrypt(e.to_string()))?;
+        Ok(serde_json::from_slice(&plain)?)
+    }
+}
+
+#[cfg(unix)]
+pub(crate) fn restrict_permissions(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+}
+
+#[cfg(not(unix))]
+pub(crate) fn restrict_permissions(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
@@ -1,3 +1,11 @@
+pub mod keystore;
+pub mod remote;
+pub mod signer;
+
+pub use keystore::{EncryptedFile, KeystoreError};
+pub use remote::RemoteSigner;
+pub use signer::{AbstractSigner, LocalSigner, SignerError};
+
 use base64::Engine;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
@@ -5,7 +13,7 @@ use std::collections::HashMap;
 
 pub type HmacSha256 = Hmac<Sha256>;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct BuilderApiKeyCreds {
     pub key: String,
     pub secret: String, // base64-encoded secret
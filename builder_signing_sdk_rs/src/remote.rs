@@ -0,0 +1,308 @@
+use crate::signer::{AbstractSigner, SignerError};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `AbstractSigner` that forwards signing to an external JSON-RPC service (Web3Signer, Clef or
+/// any node-style signer), so the private key never enters this process.
+///
+/// Methods used:
+/// - typed data: `eth_signTypedData_v4` with `[address, typedData]`
+/// - messages: `personal_sign` with `[0x-message, address]`
+/// - digests: `[address, 0x-digest]` with the method set by `with_digest_method`; there is no
+///   default because standard `eth_sign` adds the EIP-191 prefix
+pub struct RemoteSigner {
+    url: String,
+    address: String,
+    http: reqwest::Client,
+    headers: HashMap<String, String>,
+    digest_method: Option<String>,
+    next_id: AtomicU64,
+}
+
+impl RemoteSigner {
+    /// Signer for a known account on the service at `url`. Requests time out after 30s
+    /// (5s to connect); use `with_http_client` for other limits.
+    pub fn new(url: impl Into<String>, address: impl Into<String>) -> Self {
+        let http = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            url: url.into(),
+            address: address.into().to_lowercase(),
+            http,
+            headers: HashMap::new(),
+            digest_method: None,
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Use the first account reported by `eth_accounts` (services without extra auth headers;
+    /// otherwise use `new` with the address).
+    pub async fn connect(url: impl Into<String>) -> Result<Self, SignerError> {
+        let mut signer = Self::new(url, "");
+        let accounts = signer.call("eth_accounts", json!([])).await?;
+        signer.address = accounts
+            .as_array()
+            .and_then(|a| a.first())
+            .and_then(|a| a.as_str())
+            .ok_or_else(|| SignerError::Remote("eth_accounts returned no account".to_string()))?
+            .to_lowercase();
+        Ok(signer)
+    }
+
+    /// Reuse a preconfigured `reqwest::Client` (timeouts, TLS, proxy).
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Extra header sent with every request, e.g. `Authorization: Bearer ...`.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// RPC method for `sign_digest`, which fails until one is set. The service must sign the
+    /// 32 bytes as is: plain `eth_sign` implementations that add the EIP-191 prefix produce
+    /// signatures the relayer and exchange reject.
+    pub fn with_digest_method(mut self, method: impl Into<String>) -> Self {
+        self.digest_method = Some(method.into());
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value, SignerError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": self.next_id.fetch_add(1, Ordering::Relaxed),
+            "method": method,
+            "params": params,
+        });
+        let mut req = self.http.post(&self.url).json(&body);
+        for (k, v) in self.headers.iter() {
+            req = req.header(k, v);
+        }
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| SignerError::Remote(format!("{}: {}", method, e)))?;
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| SignerError::Remote(format!("{}: {}", method, e)))?;
+        if !status.is_success() {
+            return Err(SignerError::Remote(format!(
+                "{}: HTTP {} {}",
+                method, status, text
+            )));
+        }
+        let mut val: Value = serde_json::from_str(&text)
+            .map_err(|e| SignerError::Remote(format!("{}: invalid response: {}", method, e)))?;
+        if let Some(err) = val.get("error").filter(|e| !e.is_null()) {
+            let msg = err.get("message").and_then(|m| m.as_str()).unwrap_or("");
+            return Err(SignerError::Remote(format!("{}: {} {}", method, msg, err)));
+        }
+        Ok(val
+            .get_mut("result")
+            .map(Value::take)
+            .unwrap_or(Value::Null))
+    }
+
+    async fn call_signature(&self, method: &str, params: Value) -> Result<String, SignerError> {
+        let result = self.call(method, params).await?;
+        let sig = result.as_str().ok_or_else(|| {
+            SignerError::Remote(format!("{}: expected signature, got {}", method, result))
+        })?;
+        normalize_signature(sig)
+    }
+}
+
+/// `0x`-prefixed, 65 bytes, `v` in 27/28 (some services return 0/1).
+fn normalize_signature(sig: &str) -> Result<String, SignerError> {
+    let hex_part = sig.trim().trim_start_matches("0x");
+    let mut bytes = hex::decode(hex_part)
+        .map_err(|e| SignerError::Remote(format!("invalid signature {:?}: {}", sig, e)))?;
+    if bytes.len() != 65 {
+        return Err(SignerError::Remote(format!(
+            "invalid signature length {}",
+            bytes.len()
+        )));
+    }
+    if bytes[64] < 27 {
+        bytes[64] += 27;
+    }
+    Ok(format!("0x{}", hex::encode(bytes)))
+}
+
+#[async_trait]
+impl AbstractSigner for RemoteSigner {
+    async fn address(&self) -> Result<String, SignerError> {
+        Ok(self.address.clone())
+    }
+
+    async fn sign_typed_data(&self, typed_data: &Value) -> Result<String, SignerError> {
+        self.call_signature("eth_signTypedData_v4", json!([self.address, typed_data]))
+            .await
+    }
+
+    async fn sign_digest(&self, digest: [u8; 32]) -> Result<String, SignerError> {
+        let method = self.digest_method.as_deref().ok_or_else(|| {
+            SignerError::Signing(
+                "remote signer has no raw digest method, see RemoteSigner::with_digest_method"
+                    .to_string(),
+            )
+        })?;
+        let data = format!("0x{}", hex::encode(digest));
+        self.call_signature(method, json!([self.address, data]))
+            .await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<String, SignerError> {
+        let data = format!("0x{}", hex::encode(message));
+        self.call_signature("personal_sign", json!([data, self.address]))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::LocalSigner;
+    use ethers::types::Signature;
+    use ethers::utils::hash_message;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn forwards_requests_to_rpc_service() {
+        let local =
+            LocalSigner::new("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+                .unwrap();
+        let address = local.address().await.unwrap();
+        // the service answers with v = 0/1, as some signers do
+        let mut raw = hex::decode(&local.sign_message(b"hello").await.unwrap()[2..]).unwrap();
+        raw[64] -= 27;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .json_body_partial(r#"{"method":"eth_accounts"}"#);
+                then.status(200)
+                    .json_body(json!({"jsonrpc":"2.0","id":1,"result":[address]}));
+            })
+            .await;
+        let personal = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .header("authorization", "Bearer token")
+                    .json_body_partial(
+                        json!({"method":"personal_sign","params":["0x68656c6c6f", address]})
+                            .to_string(),
+                    );
+                then.status(200).json_body(
+                    json!({"jsonrpc":"2.0","id":2,"result":format!("0x{}", hex::encode(&raw))}),
+                );
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .json_body_partial(r#"{"method":"eth_signTypedData_v4"}"#);
+                then.status(200).json_body(
+                    json!({"jsonrpc":"2.0","id":3,"error":{"code":-32000,"message":"locked"}}),
+                );
+            })
+            .await;
+
+        let signer = RemoteSigner::connect(server.url("/"))
+            .await
+            .unwrap()
+            .with_header("Authorization", "Bearer token");
+        assert_eq!(signer.address().await.unwrap(), address);
+
+        let sig = signer.sign_message(b"hello").await.unwrap();
+        personal.assert_async().await;
+        let parsed: Signature = sig.parse().unwrap();
+        assert_eq!(parsed.v, raw[64] as u64 + 27);
+        assert_eq!(
+            format!("{:#x}", parsed.recover(hash_message(b"hello")).unwrap()),
+            address
+        );
+
+        let err = signer
+            .sign_typed_data(&json!({"types": {}}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("locked"));
+    }
+
+    #[tokio::test]
+    async fn signs_typed_data_and_raw_digests() {
+        let local =
+            LocalSigner::new("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+                .unwrap();
+        let address = local.address().await.unwrap();
+        let typed = json!({
+            "types": {
+                "EIP712Domain": [{"name": "name", "type": "string"}],
+                "Mail": [{"name": "contents", "type": "string"}]
+            },
+            "domain": {"name": "Test"},
+            "primaryType": "Mail",
+            "message": {"contents": "hi"}
+        });
+        let digest = [7u8; 32];
+        let typed_sig = local.sign_typed_data(&typed).await.unwrap();
+        let digest_sig = local.sign_digest(digest).await.unwrap();
+
+        let server = MockServer::start_async().await;
+        let typed_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).json_body_partial(
+                    json!({"method":"eth_signTypedData_v4","params":[address, typed]}).to_string(),
+                );
+                then.status(200)
+                    .json_body(json!({"jsonrpc":"2.0","id":1,"result":typed_sig}));
+            })
+            .await;
+        let digest_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).json_body_partial(
+                    json!({
+                        "method": "eth_signHash",
+                        "params": [address, format!("0x{}", hex::encode(digest))]
+                    })
+                    .to_string(),
+                );
+                then.status(200)
+                    .json_body(json!({"jsonrpc":"2.0","id":2,"result":digest_sig}));
+            })
+            .await;
+
+        let signer = RemoteSigner::new(server.url("/"), address.clone());
+        assert_eq!(signer.sign_typed_data(&typed).await.unwrap(), typed_sig);
+        typed_mock.assert_async().await;
+
+        // no request is made until a raw digest method is configured
+        assert!(matches!(
+            signer.sign_digest(digest).await,
+            Err(SignerError::Signing(m)) if m.contains("with_digest_method")
+        ));
+        assert_eq!(digest_mock.hits_async().await, 0);
+        let signer = signer.with_digest_method("eth_signHash");
+        assert_eq!(signer.sign_digest(digest).await.unwrap(), digest_sig);
+        digest_mock.assert_async().await;
+    }
+}
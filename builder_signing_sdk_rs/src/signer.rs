@@ -0,0 +1,223 @@
+use crate::keystore::KeystoreError;
+use async_trait::async_trait;
+use ethers::core::types::transaction::eip712::TypedData;
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::{H256, Signature};
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("invalid private key: {0}")]
+    InvalidKey(String),
+    #[error("invalid payload: {0}")]
+    InvalidPayload(String),
+    #[error("signing failed: {0}")]
+    Signing(String),
+    /// Remote signing service unreachable or returned an error.
+    #[error("remote signer: {0}")]
+    Remote(String),
+}
+
+/// Async signer shared by the CLOB and relayer clients. Implement it once for a custody backend
+/// (local key, KMS, remote wallet) and pass the same `Arc<dyn AbstractSigner>` to both.
+///
+/// Signatures are returned as `0x{r}{s}{v}` hex with `v` in 27/28.
+#[async_trait]
+pub trait AbstractSigner: Send + Sync {
+    /// 0x-prefixed lowercase address.
+    async fn address(&self) -> Result<String, SignerError>;
+
+    /// Sign EIP-712 typed data given as a full JSON document
+    /// (`types`, `domain`, `primaryType`, `message`).
+    async fn sign_typed_data(&self, typed_data: &serde_json::Value) -> Result<String, SignerError>;
+
+    /// Sign a 32-byte digest as is, without any prefix (e.g. a precomputed EIP-712 digest).
+    async fn sign_digest(&self, digest: [u8; 32]) -> Result<String, SignerError>;
+
+    /// EIP-191 `personal_sign` over `message`.
+    async fn sign_message(&self, message: &[u8]) -> Result<String, SignerError>;
+}
+
+#[async_trait]
+impl<T: AbstractSigner + ?Sized> AbstractSigner for Arc<T> {
+    async fn address(&self) -> Result<String, SignerError> {
+        (**self).address().await
+    }
+
+    async fn sign_typed_data(&self, typed_data: &serde_json::Value) -> Result<String, SignerError> {
+        (**self).sign_typed_data(typed_data).await
+    }
+
+    async fn sign_digest(&self, digest: [u8; 32]) -> Result<String, SignerError> {
+        (**self).sign_digest(digest).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<String, SignerError> {
+        (**self).sign_message(message).await
+    }
+}
+
+/// `AbstractSigner` backed by a private key held in memory.
+#[derive(Clone)]
+pub struct LocalSigner {
+    wallet: Arc<LocalWallet>,
+}
+
+impl LocalSigner {
+    /// Hex private key, with or without `0x`.
+    pub fn new(priv_key_hex: &str) -> Result<Self, SignerError> {
+        let wallet: LocalWallet = priv_key_hex
+            .trim()
+            .trim_start_matches("0x")
+            .parse()
+            .map_err(|e| SignerError::InvalidKey(format!("{}", e)))?;
+        Ok(Self::from_wallet(wallet))
+    }
+
+    /// Decrypt a standard Ethereum JSON keystore (scrypt or pbkdf2), as written by geth, Foundry
+    /// `cast wallet` or `save_keystore`.
+    pub fn from_keystore(
+        path: impl AsRef<Path>,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Self, SignerError> {
+        let path = path.as_ref();
+        let wallet = LocalWallet::decrypt_keystore(path, password)
+            .map_err(|e| SignerError::InvalidKey(format!("{}: {}", path.display(), e)))?;
+        Ok(Self::from_wallet(wallet))
+    }
+
+    /// Write the key to `path` as an encrypted keystore (scrypt, owner read/write only).
+    pub fn save_keystore(
+        &self,
+        path: impl AsRef<Path>,
+        password: impl AsRef<[u8]>,
+    ) -> Result<(), KeystoreError> {
+        let path = path.as_ref();
+        let dir = match path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+        let name = path.file_name().and_then(|n| n.to_str());
+        std::fs::create_dir_all(dir)?;
+        let secret = self.wallet.signer().to_bytes();
+        eth_keystore::encrypt_key(
+            dir,
+            &mut rand::thread_rng(),
+            &secret[..],
+            password,
+            name,
+        )
+        .map_err(|e| KeystoreError::Decrypt(e.to_string()))?;
+        crate::keystore::restrict_permissions(path)?;
+        Ok(())
+    }
+
+    pub fn from_wallet(wallet: LocalWallet) -> Self {
+        Self {
+            wallet: Arc::new(wallet),
+        }
+    }
+
+    pub fn wallet(&self) -> Arc<LocalWallet> {
+        self.wallet.clone()
+    }
+}
+
+fn to_hex(sig: Signature) -> String {
+    format!("0x{}", sig)
+}
+
+#[async_trait]
+impl AbstractSigner for LocalSigner {
+    async fn address(&self) -> Result<String, SignerError> {
+        Ok(format!("{:#x}", self.wallet.address()))
+    }
+
+    async fn sign_typed_data(&self, typed_data: &serde_json::Value) -> Result<String, SignerError> {
+        let typed: TypedData = serde_json::from_value(typed_data.clone())
+            .map_err(|e| SignerError::InvalidPayload(format!("typed data: {}", e)))?;
+        let sig = self
+            .wallet
+            .sign_typed_data(&typed)
+            .await
+            .map_err(|e| SignerError::Signing(e.to_string()))?;
+        Ok(to_hex(sig))
+    }
+
+    async fn sign_digest(&self, digest: [u8; 32]) -> Result<String, SignerError> {
+        let sig = self
+            .wallet
+            .sign_hash(H256::from(digest))
+            .map_err(|e| SignerError::Signing(e.to_string()))?;
+        Ok(to_hex(sig))
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<String, SignerError> {
+        let sig = self
+            .wallet
+            .sign_message(message)
+            .await
+            .map_err(|e| SignerError::Signing(e.to_string()))?;
+        Ok(to_hex(sig))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::utils::{hash_message, keccak256};
+
+    #[tokio::test]
+    async fn local_signer_signatures_recover_to_address() {
+        let signer: Arc<dyn AbstractSigner> = Arc::new(
+            LocalSigner::new("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+                .unwrap(),
+        );
+        let address = signer.address().await.unwrap();
+        assert_eq!(address, "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+
+        let recover = |sig: &str, hash: H256| {
+            let sig: Signature = sig.parse().unwrap();
+            assert!(sig.v == 27 || sig.v == 28);
+            format!("{:#x}", sig.recover(hash).unwrap())
+        };
+        let digest = keccak256(b"digest");
+        let sig = signer.sign_digest(digest).await.unwrap();
+        assert_eq!(recover(&sig, H256::from(digest)), address);
+
+        let sig = signer.sign_message(b"hello").await.unwrap();
+        assert_eq!(recover(&sig, hash_message(b"hello")), address);
+
+        let typed = serde_json::json!({
+            "types": {
+                "EIP712Domain": [{"name": "name", "type": "string"}],
+                "Mail": [{"name": "contents", "type": "string"}]
+            },
+            "domain": {"name": "Test"},
+            "primaryType": "Mail",
+            "message": {"contents": "hi"}
+        });
+        let sig = signer.sign_typed_data(&typed).await.unwrap();
+        let parsed: TypedData = serde_json::from_value(typed).unwrap();
+        let hash = ethers::types::transaction::eip712::Eip712::encode_eip712(&parsed).unwrap();
+        assert_eq!(recover(&sig, H256::from(hash)), address);
+    }
+
+    #[tokio::test]
+    async fn keystore_round_trip() {
+        let signer =
+            LocalSigner::new("0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d")
+                .unwrap();
+        let path = std::env::temp_dir().join(format!("signer-keystore-{}", std::process::id()));
+        signer.save_keystore(&path, "hunter2").unwrap();
+        let loaded = LocalSigner::from_keystore(&path, "hunter2").unwrap();
+        let wrong = LocalSigner::from_keystore(&path, "wrong");
+        std::fs::remove_file(&path).ok();
+        assert_eq!(
+            loaded.address().await.unwrap(),
+            signer.address().await.unwrap()
+        );
+        assert!(matches!(wrong, Err(SignerError::InvalidKey(_))));
+    }
+}
@@ -160,7 +160,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!();
 
     // 计算订单 hash
-    let order_hash = builder.build_order_hash(&typed_data);
+    let order_hash = builder.build_order_hash(&typed_data)?;
     println!("Order Hash: {}", order_hash);
     println!();
 
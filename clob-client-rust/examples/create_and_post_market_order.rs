@@ -2,6 +2,7 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::BuilderConfig;
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{OrderType, Side, SignatureType, UserMarketOrder};
+use rust_decimal_macros::dec;
 use std::sync::Arc;
 
 // Example: create + post a market order using convenience method with auto tick resolution
@@ -60,16 +61,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let price = clob_client_rust::order_builder::compute_market_price_from_book(
         &book,
         Side::BUY,
-        2.5,
+        dec!(2.5),
         OrderType::FOK,
     )?;
 
     let user_market_order = UserMarketOrder {
         token_id: token_id.clone(),
         price,
-        amount: 2.5,
+        amount: dec!(2.5),
         side: Side::BUY,
-        fee_rate_bps: 1.0,
+        fee_rate_bps: None, // 使用市场基础费率
         nonce: None,
         taker: None,
         order_type: OrderType::FOK,
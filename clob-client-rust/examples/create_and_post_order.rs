@@ -2,6 +2,7 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::BuilderConfig;
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{Side, SignatureType, UserOrder};
+use rust_decimal_macros::dec;
 use std::sync::Arc;
 
 // Example: create + post a limit order using BuilderConfig convenience method.
@@ -43,7 +44,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         false,
     )
     .with_builder_config(BuilderConfig {
-        tick_size: Some("0.001".to_string()),
+        tick_size: None, // 按 token 查询市场 tick size
         neg_risk: None,
         signature_type: SignatureType::EOA,
         funder_address: None,
@@ -60,10 +61,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let user_order = UserOrder {
         token_id: token_id.clone(),
-        price: 0.01,
-        size: 5.0,
+        price: dec!(0.01),
+        size: dec!(5),
         side: Side::BUY,
-        fee_rate_bps: 1.0,
+        fee_rate_bps: None, // 使用市场基础费率
         nonce: None,
         expiration: None,
         taker: None,
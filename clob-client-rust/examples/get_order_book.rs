@@ -14,7 +14,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .and_then(|v| v.parse().ok())
         .unwrap_or(80002);
 
-    let mut client = ClobClient::new(&host, chain_id, None, None, false);
+    let client = ClobClient::new(&host, chain_id, None, None, false);
     let ob = client.get_order_book(&token_id).await?;
     println!(
         "OrderBook market={} bids={} asks={} hash={}",
@@ -20,6 +20,8 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::BuilderConfig;
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{ApiKeyCreds, Side, SignatureType, UserOrder};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use std::sync::Arc;
 
 #[tokio::main]
@@ -45,15 +47,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .or_else(|| std::env::var("TOKEN_ID").ok())
         .expect("需要提供 TOKEN_ID (作为参数或环境变量)");
 
-    let price: f64 = std::env::args()
+    let price: Decimal = std::env::args()
         .nth(2)
         .and_then(|s| s.parse().ok())
-        .unwrap_or(0.58); // 默认价格
+        .unwrap_or(dec!(0.58)); // 默认价格
 
-    let size: f64 = std::env::args()
+    let size: Decimal = std::env::args()
         .nth(3)
         .and_then(|s| s.parse().ok())
-        .unwrap_or(10.0); // 默认数量
+        .unwrap_or(dec!(10)); // 默认数量
 
     println!("配置:");
     println!("  Host: {}", host);
@@ -77,8 +79,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         false,
     )
     .with_builder_config(BuilderConfig {
-        tick_size: Some("0.01".to_string()),
-        neg_risk: Some(false),
+        tick_size: None, // 按 token 查询市场 tick size
+        neg_risk: None,  // 按 token 查询 neg_risk 并选择交易所合约
         signature_type: SignatureType::EOA,
         funder_address: None,
     });
@@ -104,11 +106,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         token_id: token_id.clone(),
         price,
         size,
-        side: Side::SELL,  // 注意这里是 SELL
-        fee_rate_bps: 1.0, // 0.01% 费率 (必填)
-        nonce: None,       // 自动生成
-        expiration: None,  // 使用默认过期时间
-        taker: None,       // 任何人可成交
+        side: Side::SELL,   // 注意这里是 SELL
+        fee_rate_bps: None, // 未指定时使用市场基础费率
+        nonce: None,        // 自动生成
+        expiration: None,   // 使用默认过期时间
+        taker: None,        // 任何人可成交
     };
 
     println!("订单信息:");
@@ -116,7 +118,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  方向: 卖单 (SELL)");
     println!("  价格: {}", user_order.price);
     println!("  数量: {}", user_order.size);
-    println!("  费率: {}bps", user_order.fee_rate_bps);
+    println!("  费率: {:?}", user_order.fee_rate_bps);
     println!();
 
     // 6. 提交订单
@@ -22,6 +22,8 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::BuilderConfig;
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{ApiKeyCreds, OrderType, Side, SignatureType, UserMarketOrder};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use std::sync::Arc;
 
 #[tokio::main]
@@ -47,10 +49,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .or_else(|| std::env::var("TOKEN_ID").ok())
         .expect("需要提供 TOKEN_ID (作为参数或环境变量)");
 
-    let amount: f64 = std::env::args()
+    let amount: Decimal = std::env::args()
         .nth(2)
         .and_then(|s| s.parse().ok())
-        .unwrap_or(5.0); // 默认数量
+        .unwrap_or(dec!(5)); // 默认数量
 
     println!("配置:");
     println!("  Host: {}", host);
@@ -111,7 +113,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         price, // 必填外部计算
         amount,
         side: Side::SELL,           // 注意这里是 SELL
-        fee_rate_bps: 0.0,          // 示例费率
+        fee_rate_bps: None,         // 未指定时使用市场基础费率
         nonce: None,                // 自动生成
         taker: None,                // 任何人可成交
         order_type: OrderType::FOK, // 必填
@@ -122,7 +124,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  方向: 卖单 (SELL)");
     println!("  类型: 市价单 - FOK (Fill or Kill)");
     println!("  数量: {}", user_market_order.amount);
-    println!("  费率: {} bps", user_market_order.fee_rate_bps);
+    println!("  费率: {:?}", user_market_order.fee_rate_bps);
     println!("\n⚠️  市价单将以当前最佳买价立即成交");
     println!("⚠️  确保您有足够的持仓进行卖出");
     println!("⚠️  FOK 订单类型: 必须全部成交,否则取消");
@@ -160,11 +162,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 if let (Ok(making_amt), Ok(taking_amt)) =
                     (making_str.parse::<f64>(), taking_str.parse::<f64>())
+                    && taking_amt > 0.0
                 {
-                    if taking_amt > 0.0 {
-                        let avg_price = making_amt / taking_amt;
-                        println!("  实际成交价: ~{:.4}", avg_price);
-                    }
+                    let avg_price = making_amt / taking_amt;
+                    println!("  实际成交价: ~{:.4}", avg_price);
                 }
             }
 
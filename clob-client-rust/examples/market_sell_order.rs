@@ -2,6 +2,7 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::{OrderBuilder, compute_market_price_from_book};
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{OrderType, Side, SignatureType, UserMarketOrder};
+use rust_decimal_macros::dec;
 // no extra imports
 
 // Example: build a market SELL order
@@ -29,16 +30,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or(80002);
 
     // 外部获取订单簿并计算市价价格
-    let mut client = ClobClient::new(&host, chain_id, None, None, false);
+    let client = ClobClient::new(&host, chain_id, None, None, false);
     let book = client.getOrderBook(&token_id).await?;
-    let price = compute_market_price_from_book(&book, Side::SELL, 5.0, OrderType::FOK)?;
+    let price = compute_market_price_from_book(&book, Side::SELL, dec!(5), OrderType::FOK)?;
 
     let mso = UserMarketOrder {
         token_id,
         price,
-        amount: 5.0,
+        amount: dec!(5),
         side: Side::SELL,
-        fee_rate_bps: 1.0,
+        fee_rate_bps: Some(1.0),
         nonce: None,
         taker: None,
         order_type: OrderType::FOK,
@@ -2,6 +2,7 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::OrderBuilder;
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{Side, SignatureType, UserOrder};
+use rust_decimal_macros::dec;
 
 // Example: build and post a limit order.
 // Requirements: set PK (private key hex), optionally CLOB_API_URL and CHAIN_ID.
@@ -30,10 +31,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let user_order = UserOrder {
         token_id: token_id.clone(),
-        price: 0.01,
-        size: 5.0,
+        price: dec!(0.01),
+        size: dec!(5),
         side: Side::BUY,
-        fee_rate_bps: 1.0,
+        fee_rate_bps: Some(1.0),
         nonce: None,
         expiration: None,
         taker: None,
@@ -39,7 +39,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 2. 初始化 Client
     let signer = Arc::new(EthersSigner::new_from_private_key(&pk)?);
-    let mut client = ClobClient::new(
+    let client = ClobClient::new(
         &host,
         chain_id,
         Some(signer.clone()),
@@ -2,6 +2,7 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::OrderBuilder;
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{ApiKeyCreds, OrderType, Side, UserMarketOrder};
+use rust_decimal_macros::dec;
 use serde_json::json;
 use std::env;
 use std::sync::Arc;
@@ -24,13 +25,13 @@ async fn main() {
 
     let user_market_order = UserMarketOrder {
         token_id: "1".to_string(),
-        price: 1.23,
-        amount: 0.5,
+        price: dec!(0.5),
+        amount: dec!(5),
         side: Side::BUY,
-        fee_rate_bps: 1.0,
+        fee_rate_bps: Some(1.0),
         nonce: None,
         taker: None,
-        order_type: OrderType::GTC,
+        order_type: OrderType::FOK,
     };
 
     let signed = match ob
@@ -2,6 +2,7 @@ use clob_client_rust::client::ClobClient;
 use clob_client_rust::order_builder::BuilderConfig;
 use clob_client_rust::signer_adapter::EthersSigner;
 use clob_client_rust::types::{ApiKeyCreds, Side, SignatureType, UserOrder};
+use rust_decimal_macros::dec;
 use std::sync::Arc;
 
 /// Example demonstrating the use of different SignatureType variants
@@ -115,10 +116,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create sample orders with different signature types
     let _user_order = UserOrder {
         token_id: "1234".to_string(),
-        price: 0.5,
-        size: 10.0,
+        price: dec!(0.5),
+        size: dec!(10),
         side: Side::BUY,
-        fee_rate_bps: 0.0,
+        fee_rate_bps: None,
         nonce: None,
         expiration: None,
         taker: None,
@@ -0,0 +1,54 @@
+use clob_client_rust::ws::{MarketChannel, MarketEvent, WS_MARKET_URL, WsConfig};
+use futures_util::StreamExt;
+
+// Example: stream market channel events for one or more token ids
+// Run: TOKEN_IDS=<id1>,<id2> cargo run --example ws_market
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let url = std::env::var("CLOB_WS_URL").unwrap_or_else(|_| WS_MARKET_URL.to_string());
+    let token_ids: Vec<String> = std::env::var("TOKEN_IDS")
+        .expect("TOKEN_IDS env var required (comma separated)")
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let (handle, mut events) = MarketChannel::connect(WsConfig::new(url), token_ids);
+
+    let mut remaining = 20;
+    while let Some(ev) = events.next().await {
+        match ev {
+            MarketEvent::Book(b) => println!(
+                "book {} bids={} asks={} hash={}",
+                b.asset_id,
+                b.bids.len(),
+                b.asks.len(),
+                b.hash
+            ),
+            MarketEvent::PriceChange(p) => {
+                for c in p.price_changes {
+                    println!(
+                        "price_change {} {:?} {}@{}",
+                        c.asset_id, c.side, c.size, c.price
+                    );
+                }
+            }
+            MarketEvent::TickSizeChange(t) => println!(
+                "tick_size_change {} {} -> {}",
+                t.asset_id, t.old_tick_size, t.new_tick_size
+            ),
+            MarketEvent::LastTradePrice(t) => {
+                println!(
+                    "last_trade {} {:?} {}@{}",
+                    t.asset_id, t.side, t.size, t.price
+                )
+            }
+            MarketEvent::Reconnected => println!("reconnected"),
+        }
+        remaining -= 1;
+        if remaining == 0 {
+            handle.close()?;
+        }
+    }
+    Ok(())
+}
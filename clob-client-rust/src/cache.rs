@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Concurrent map with optional expiry, used for per-token market metadata (tick size,
+/// neg-risk flag, fee rate, minimum order size).
+///
+/// Clones share the same entries, so every clone of a `ClobClient` sees the same cache.
+/// Expired entries are dropped when a new key is inserted, and once `max_entries` is reached
+/// the oldest entry makes room, so a process cycling through many markets stays bounded.
+#[derive(Debug)]
+pub struct TtlCache<K, V> {
+    entries: Arc<RwLock<HashMap<K, (V, Instant)>>>,
+    ttl: Option<Duration>,
+    max_entries: usize,
+}
+
+/// Entry cap used by `TtlCache::new`.
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+impl<K, V> Clone for TtlCache<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            ttl: self.ttl,
+            max_entries: self.max_entries,
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Default for TtlCache<K, V> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
+    /// `ttl: None` keeps entries until they are invalidated or evicted by the size cap.
+    pub fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(HashMap::new())),
+            ttl,
+            max_entries: DEFAULT_MAX_ENTRIES,
+        }
+    }
+
+    /// Keep at most `max_entries` (at least 1), evicting the oldest first.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Cached value, unless it is older than the TTL.
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        let (value, at) = entries.get(key)?;
+        match self.ttl {
+            Some(ttl) if at.elapsed() >= ttl => None,
+            _ => Some(value.clone()),
+        }
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if !entries.contains_key(&key) {
+            if let Some(ttl) = self.ttl {
+                entries.retain(|_, (_, at)| now.duration_since(*at) < ttl);
+            }
+            if entries.len() >= self.max_entries {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, (_, at))| *at)
+                    .map(|(k, _)| k.clone());
+                if let Some(k) = oldest {
+                    entries.remove(&k);
+                }
+            }
+        }
+        entries.insert(key, (value, now));
+    }
+
+    /// Drop one entry so the next lookup goes to the server.
+    pub fn invalidate<Q>(&self, key: &Q)
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(key);
+    }
+
+    pub fn clear(&self) {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Number of stored entries, expired ones included.
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_and_invalidates_entries() {
+        let cache: TtlCache<String, String> = TtlCache::default();
+        cache.insert("1".into(), "0.01".into());
+        let shared = cache.clone();
+        assert_eq!(shared.get("1").as_deref(), Some("0.01"));
+        shared.invalidate("1");
+        assert_eq!(cache.get("1"), None);
+
+        let expiring: TtlCache<String, bool> = TtlCache::new(Some(Duration::ZERO));
+        expiring.insert("1".into(), true);
+        assert_eq!(expiring.get("1"), None);
+        assert_eq!(expiring.len(), 1);
+        // the expired entry goes as soon as another token is cached
+        expiring.insert("2".into(), true);
+        assert_eq!(expiring.len(), 1);
+    }
+
+    #[test]
+    fn evicts_oldest_entry_at_capacity() {
+        let cache: TtlCache<String, u32> = TtlCache::default().with_max_entries(2);
+        cache.insert("1".into(), 1);
+        std::thread::sleep(Duration::from_millis(1));
+        cache.insert("2".into(), 2);
+        // refreshing an existing key never evicts
+        cache.insert("2".into(), 20);
+        assert_eq!(cache.len(), 2);
+        cache.insert("3".into(), 3);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("1"), None);
+        assert_eq!(cache.get("2"), Some(20));
+        assert_eq!(cache.get("3"), Some(3));
+    }
+}
@@ -1,18 +1,32 @@
+use crate::cache::TtlCache;
+use crate::clock::{ServerClock, local_millis};
+use crate::config::{ClobClientBuilder, ContractConfig};
 use crate::constants::{END_CURSOR, INITIAL_CURSOR};
+use crate::credentials::CredentialSink;
 use crate::endpoints::*;
 use crate::errors::ClobError;
 // use crate::exchange_consts::ZERO_ADDRESS; // no longer needed; we resolve real exchange addresses dynamically
-use crate::http_helpers::{RequestOptions, get, post};
-use crate::order_builder::{BuilderConfig as ObBuilderConfig, OrderBuilder};
-use crate::signer_adapter::EthersSigner;
+use crate::http_helpers::{DELETE, GET, HttpClientConfig, POST, RequestOptions};
+use crate::order_book::LocalOrderBook;
+use crate::order_builder::{
+    BuilderConfig as ObBuilderConfig, OrderBuilder, validate_market_order, validate_order,
+};
+use crate::rate_limit::{GroupBudget, RateLimitConfig, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::signer_adapter::AbstractSigner;
+use crate::transport::{ReqwestTransport, Transport};
 use crate::types::OrderBookSummary;
 use crate::types::{ApiKeyCreds, ApiKeyRaw};
 use crate::types::{
     Notification, OpenOrder, Order, OrderResponse, OrderType, Reward, SignedOrder, Trade,
     UserMarketOrder, UserOrder,
 };
+use crate::utilities::is_tick_size_smaller;
+use crate::ws::{TickSizeChangeEvent, UserChannel, UserStream, WsConfig, WsHandle};
 // Removed unused alias import (Signer) after refactor; keep file clean
-use serde::Deserialize;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 #[allow(dead_code)]
@@ -58,22 +72,46 @@ impl<T> MaybeItem<T> {
         }
     }
 }
-use std::collections::HashMap;
-use std::sync::Arc;
 
+// Deserialize an already-fetched JSON value, keeping the raw body on failure.
+fn decode_value<T: DeserializeOwned>(endpoint: &str, val: Value) -> Result<T, ClobError> {
+    T::deserialize(&val).map_err(|e| ClobError::decode(endpoint, e, val.to_string()))
+}
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Client for the CLOB REST API. Cheap to clone: clones share the transport, rate limiter and
+/// metadata caches, so one client can be handed to many tasks.
+#[derive(Clone)]
 pub struct ClobClient {
     pub host: String,
     pub chain_id: i64,
-    pub signer: Option<Arc<EthersSigner>>,
-    pub creds: Option<ApiKeyCreds>,
+    pub signer: Option<Arc<dyn AbstractSigner>>,
     pub use_server_time: bool,
-    pub tick_sizes: HashMap<String, String>,
-    pub neg_risk: HashMap<String, bool>,
-    pub fee_rates: HashMap<String, f64>,
+    // Per-token market metadata, shared between clones
+    pub tick_sizes: TtlCache<String, String>,
+    pub neg_risk: TtlCache<String, bool>,
+    pub fee_rates: TtlCache<String, f64>,
+    pub min_order_sizes: TtlCache<String, Decimal>,
     // Optional Builder signer for builder-authenticated flows
-    pub builder_signer: Option<builder_signing_sdk_rs::BuilderSigner>,
+    pub builder_signer: Option<Arc<builder_signing_sdk_rs::BuilderSigner>>,
     // Optional default builder config for order creation
     pub builder_config: Option<ObBuilderConfig>,
+    // Contract addresses of the chain; None for chains without preset
+    contracts: Option<ContractConfig>,
+    // Offset to the server clock, used for auth timestamps when use_server_time is set
+    server_clock: ServerClock,
+    // Transport shared by every request; defaults to a pooled reqwest client
+    transport: Arc<dyn Transport>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    // L2 creds, shared between clones so a bootstrap or rotation is seen by all of them
+    creds: Arc<RwLock<Option<ApiKeyCreds>>>,
+    // Create / derive L2 creds from the signer on first authenticated call
+    auto_api_key: bool,
+    credential_sink: Option<Arc<dyn CredentialSink>>,
+    creds_bootstrap: Arc<tokio::sync::Mutex<()>>,
 }
 
 impl ClobClient {
@@ -102,22 +140,22 @@ impl ClobClient {
     }
 
     #[allow(non_snake_case)]
-    pub async fn getOrderBook(&mut self, token_id: &str) -> Result<OrderBookSummary, ClobError> {
+    pub async fn getOrderBook(&self, token_id: &str) -> Result<OrderBookSummary, ClobError> {
         self.get_order_book(token_id).await
     }
 
     #[allow(non_snake_case)]
-    pub async fn getTickSize(&mut self, token_id: &str) -> Result<String, ClobError> {
+    pub async fn getTickSize(&self, token_id: &str) -> Result<String, ClobError> {
         self.get_tick_size(token_id).await
     }
 
     #[allow(non_snake_case)]
-    pub async fn getNegRisk(&mut self, token_id: &str) -> Result<bool, ClobError> {
+    pub async fn getNegRisk(&self, token_id: &str) -> Result<bool, ClobError> {
         self.get_neg_risk(token_id).await
     }
 
     #[allow(non_snake_case)]
-    pub async fn getFeeRate(&mut self, token_id: &str) -> Result<f64, ClobError> {
+    pub async fn getFeeRate(&self, token_id: &str) -> Result<f64, ClobError> {
         self.get_fee_rate(token_id).await
     }
 
@@ -154,7 +192,7 @@ impl ClobClient {
 
     #[allow(non_snake_case)]
     pub async fn createOrder(
-        &mut self,
+        &self,
         user_order: UserOrder,
         options_tick: Option<&str>,
     ) -> Result<SignedOrder, ClobError> {
@@ -163,7 +201,7 @@ impl ClobClient {
 
     #[allow(non_snake_case)]
     pub async fn createMarketOrder(
-        &mut self,
+        &self,
         user_market_order: UserMarketOrder,
         options_tick: Option<&str>,
     ) -> Result<SignedOrder, ClobError> {
@@ -173,7 +211,7 @@ impl ClobClient {
 
     #[allow(non_snake_case)]
     pub async fn createAndPostOrder(
-        &mut self,
+        &self,
         user_order: UserOrder,
         options_tick: Option<&str>,
     ) -> Result<OrderResponse, ClobError> {
@@ -183,7 +221,7 @@ impl ClobClient {
 
     #[allow(non_snake_case)]
     pub async fn createAndPostMarketOrder(
-        &mut self,
+        &self,
         user_market_order: UserMarketOrder,
         options_tick: Option<&str>,
     ) -> Result<OrderResponse, ClobError> {
@@ -451,7 +489,7 @@ impl ClobClient {
     pub fn new(
         host: &str,
         chain_id: i64,
-        signer: Option<Arc<EthersSigner>>,
+        signer: Option<Arc<dyn AbstractSigner>>,
         creds: Option<ApiKeyCreds>,
         use_server_time: bool,
     ) -> Self {
@@ -463,16 +501,197 @@ impl ClobClient {
             },
             chain_id,
             signer,
-            creds,
             use_server_time,
-            tick_sizes: HashMap::new(),
-            neg_risk: HashMap::new(),
-            fee_rates: HashMap::new(),
+            tick_sizes: TtlCache::default(),
+            neg_risk: TtlCache::default(),
+            fee_rates: TtlCache::default(),
+            min_order_sizes: TtlCache::default(),
             builder_signer: None,
             builder_config: None,
+            contracts: ContractConfig::for_chain(chain_id).ok(),
+            server_clock: ServerClock::default(),
+            transport: Arc::new(ReqwestTransport::new(
+                HttpClientConfig::default()
+                    .build()
+                    .expect("default http client config is valid"),
+            )),
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: Some(Arc::new(RateLimiter::default())),
+            creds: Arc::new(RwLock::new(creds)),
+            auto_api_key: false,
+            credential_sink: None,
+            creds_bootstrap: Arc::new(tokio::sync::Mutex::new(())),
         }
     }
 
+    /// Fluent alternative to `new` with chain presets, env and config file loading.
+    pub fn builder() -> ClobClientBuilder {
+        ClobClientBuilder::default()
+    }
+
+    /// Override the contract addresses (required for chains without preset).
+    pub fn with_contracts(mut self, contracts: ContractConfig) -> Self {
+        self.contracts = Some(contracts);
+        self
+    }
+
+    pub fn contracts(&self) -> Result<&ContractConfig, ClobError> {
+        self.contracts
+            .as_ref()
+            .ok_or(ClobError::UnsupportedChain(self.chain_id))
+    }
+
+    /// Use a preconfigured `reqwest::Client` for all requests (e.g. one shared across clients).
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.transport = Arc::new(ReqwestTransport::new(http));
+        self
+    }
+
+    /// Build the HTTP client from `HttpClientConfig` (timeouts, pooling, keep-alive, proxy, default headers).
+    pub fn with_http_config(mut self, cfg: &HttpClientConfig) -> Result<Self, ClobError> {
+        self.transport = Arc::new(ReqwestTransport::from_config(cfg)?);
+        Ok(self)
+    }
+
+    /// Replace the transport entirely, e.g. with an in-memory fake exchange or a replay layer.
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub fn transport(&self) -> &Arc<dyn Transport> {
+        &self.transport
+    }
+
+    /// Configure retries. GETs are retried by default; POST/DELETE only with
+    /// `RetryPolicy::with_retry_non_idempotent(true)`. Use `RetryPolicy::none()` to disable.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Replace the default per-endpoint-group budgets.
+    pub fn with_rate_limits(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(config)));
+        self
+    }
+
+    /// Share one limiter between several clients (e.g. clients using the same API key).
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Turn off client-side rate limiting.
+    pub fn without_rate_limit(mut self) -> Self {
+        self.rate_limiter = None;
+        self
+    }
+
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Current token budget per endpoint group (empty when rate limiting is disabled).
+    pub fn rate_limit_budget(&self) -> Vec<GroupBudget> {
+        self.rate_limiter
+            .as_ref()
+            .map(|l| l.budget())
+            .unwrap_or_default()
+    }
+
+    /// Open the authenticated user channel (order / trade updates) with this client's L2 creds.
+    pub fn connect_user_channel(
+        &self,
+        config: WsConfig,
+        markets: Vec<String>,
+    ) -> Result<(WsHandle, UserStream), ClobError> {
+        let creds = self.creds().ok_or(ClobError::L2AuthNotAvailable)?;
+        Ok(UserChannel::connect(config, creds, markets))
+    }
+
+    async fn send_typed<R, B>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        options: Option<RequestOptions<B>>,
+    ) -> Result<R, ClobError>
+    where
+        R: DeserializeOwned,
+        B: Serialize,
+    {
+        let result = crate::http_helpers::send_typed(
+            self.transport.as_ref(),
+            &self.retry_policy,
+            self.rate_limiter.as_deref(),
+            method,
+            endpoint,
+            options,
+        )
+        .await;
+        // 401 may come from a skewed timestamp: drop the offset so the next auth call resyncs
+        if self.use_server_time && matches!(&result, Err(e) if e.status() == Some(401)) {
+            self.server_clock.invalidate();
+        }
+        result
+    }
+
+    async fn get_typed<R, B>(
+        &self,
+        endpoint: &str,
+        options: Option<RequestOptions<B>>,
+    ) -> Result<R, ClobError>
+    where
+        R: DeserializeOwned,
+        B: Serialize,
+    {
+        self.send_typed(GET, endpoint, options).await
+    }
+
+    async fn get(
+        &self,
+        endpoint: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Value, ClobError> {
+        self.get_typed::<Value, Value>(endpoint, options).await
+    }
+
+    async fn post_typed<R, B>(
+        &self,
+        endpoint: &str,
+        options: Option<RequestOptions<B>>,
+    ) -> Result<R, ClobError>
+    where
+        R: DeserializeOwned,
+        B: Serialize,
+    {
+        self.send_typed(POST, endpoint, options).await
+    }
+
+    async fn post(
+        &self,
+        endpoint: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Value, ClobError> {
+        self.post_typed::<Value, Value>(endpoint, options).await
+    }
+
+    async fn del_typed<R, B>(
+        &self,
+        endpoint: &str,
+        options: Option<RequestOptions<B>>,
+    ) -> Result<R, ClobError>
+    where
+        R: DeserializeOwned,
+        B: Serialize,
+    {
+        self.send_typed(DELETE, endpoint, options).await
+    }
+
     /// Configure Builder API signer (builder auth). Secret must be base64 encoded.
     pub fn with_builder_signer(
         mut self,
@@ -485,7 +704,7 @@ impl ClobClient {
             secret: secret_b64,
             passphrase,
         };
-        self.builder_signer = Some(builder_signing_sdk_rs::BuilderSigner::new(creds));
+        self.builder_signer = Some(Arc::new(builder_signing_sdk_rs::BuilderSigner::new(creds)));
         self
     }
 
@@ -495,7 +714,63 @@ impl ClobClient {
         self
     }
 
-    pub async fn get_order_book(&mut self, token_id: &str) -> Result<OrderBookSummary, ClobError> {
+    /// Current L2 credentials.
+    pub fn creds(&self) -> Option<ApiKeyCreds> {
+        self.creds.read().map(|c| c.clone()).unwrap_or_default()
+    }
+
+    /// Replace the L2 credentials of this client and all its clones.
+    pub fn set_creds(&self, creds: Option<ApiKeyCreds>) {
+        if let Ok(mut guard) = self.creds.write() {
+            *guard = creds;
+        }
+    }
+
+    /// Without L2 credentials, load them from the credential sink or `create_or_derive_api_key`
+    /// on the first authenticated call instead of failing with `L2AuthNotAvailable`.
+    pub fn with_auto_api_key(mut self, enabled: bool) -> Self {
+        self.auto_api_key = enabled;
+        self
+    }
+
+    /// Where bootstrapped and rotated L2 credentials are persisted (e.g. a `CredentialStore`).
+    pub fn with_credential_sink(mut self, sink: Arc<dyn CredentialSink>) -> Self {
+        self.credential_sink = Some(sink);
+        self
+    }
+
+    /// Expire cached market metadata (tick size, neg risk, fee rate, min order size) after
+    /// `ttl` instead of keeping it for the life of the client.
+    pub fn with_metadata_ttl(mut self, ttl: Duration) -> Self {
+        self.tick_sizes = TtlCache::new(Some(ttl));
+        self.neg_risk = TtlCache::new(Some(ttl));
+        self.fee_rates = TtlCache::new(Some(ttl));
+        self.min_order_sizes = TtlCache::new(Some(ttl));
+        self
+    }
+
+    /// Forget the cached metadata of one token; the next lookup goes to the server.
+    pub fn invalidate_market_metadata(&self, token_id: &str) {
+        self.tick_sizes.invalidate(token_id);
+        self.neg_risk.invalidate(token_id);
+        self.fee_rates.invalidate(token_id);
+        self.min_order_sizes.invalidate(token_id);
+    }
+
+    pub fn clear_market_metadata(&self) {
+        self.tick_sizes.clear();
+        self.neg_risk.clear();
+        self.fee_rates.clear();
+        self.min_order_sizes.clear();
+    }
+
+    /// Keep the tick size cache in sync with a market channel `tick_size_change` event.
+    pub fn apply_tick_size_change(&self, event: &TickSizeChangeEvent) {
+        self.tick_sizes
+            .insert(event.asset_id.clone(), event.new_tick_size.clone());
+    }
+
+    pub async fn get_order_book(&self, token_id: &str) -> Result<OrderBookSummary, ClobError> {
         let endpoint = format!("{}{}", self.host, GET_ORDER_BOOK);
         let mut params = std::collections::HashMap::new();
         params.insert("token_id".to_string(), token_id.to_string());
@@ -504,15 +779,38 @@ impl ClobClient {
             data: None,
             params: Some(params),
         };
-        let val = get(&endpoint, Some(opts)).await?;
-        let obs: OrderBookSummary =
-            serde_json::from_value(val).map_err(|e| ClobError::Other(e.to_string()))?;
+        let val = self.get(&endpoint, Some(opts)).await?;
+        let obs: OrderBookSummary = decode_value(&endpoint, val)?;
+        if let Ok(min) = Decimal::from_str(&obs.min_order_size) {
+            self.min_order_sizes.insert(token_id.to_string(), min);
+        }
         Ok(obs)
     }
 
-    pub async fn get_tick_size(&mut self, token_id: &str) -> Result<String, ClobError> {
+    /// Minimum order size (shares) of the market, from the order book; cached per token.
+    pub async fn get_min_order_size(&self, token_id: &str) -> Result<Decimal, ClobError> {
+        if let Some(v) = self.min_order_sizes.get(token_id) {
+            return Ok(v);
+        }
+        let book = self.get_order_book(token_id).await?;
+        self.min_order_sizes.get(token_id).ok_or_else(|| {
+            ClobError::decode(
+                GET_ORDER_BOOK,
+                "invalid min_order_size",
+                book.min_order_size.clone(),
+            )
+        })
+    }
+
+    /// Fetch a `/book` snapshot and seed a `LocalOrderBook` from it (also used to resync).
+    pub async fn get_local_order_book(&self, token_id: &str) -> Result<LocalOrderBook, ClobError> {
+        let summary = self.get_order_book(token_id).await?;
+        LocalOrderBook::from_summary(&summary)
+    }
+
+    pub async fn get_tick_size(&self, token_id: &str) -> Result<String, ClobError> {
         if let Some(v) = self.tick_sizes.get(token_id) {
-            return Ok(v.clone());
+            return Ok(v);
         }
         let endpoint = format!("{}{}", self.host, GET_TICK_SIZE);
         let mut params = std::collections::HashMap::new();
@@ -522,19 +820,21 @@ impl ClobClient {
             data: None,
             params: Some(params),
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let tick = val
             .get("minimum_tick_size")
             .and_then(|v| v.as_str())
-            .ok_or(ClobError::Other("invalid tick response".to_string()))?;
+            .ok_or_else(|| {
+                ClobError::decode(&endpoint, "invalid tick response", val.to_string())
+            })?;
         self.tick_sizes
             .insert(token_id.to_string(), tick.to_string());
         Ok(tick.to_string())
     }
 
-    pub async fn get_neg_risk(&mut self, token_id: &str) -> Result<bool, ClobError> {
+    pub async fn get_neg_risk(&self, token_id: &str) -> Result<bool, ClobError> {
         if let Some(v) = self.neg_risk.get(token_id) {
-            return Ok(*v);
+            return Ok(v);
         }
         let endpoint = format!("{}{}", self.host, GET_NEG_RISK);
         let mut params = std::collections::HashMap::new();
@@ -544,18 +844,20 @@ impl ClobClient {
             data: None,
             params: Some(params),
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let rr = val
             .get("neg_risk")
             .and_then(|v| v.as_bool())
-            .ok_or(ClobError::Other("invalid neg risk response".to_string()))?;
+            .ok_or_else(|| {
+                ClobError::decode(&endpoint, "invalid neg risk response", val.to_string())
+            })?;
         self.neg_risk.insert(token_id.to_string(), rr);
         Ok(rr)
     }
 
-    pub async fn get_fee_rate(&mut self, token_id: &str) -> Result<f64, ClobError> {
+    pub async fn get_fee_rate(&self, token_id: &str) -> Result<f64, ClobError> {
         if let Some(v) = self.fee_rates.get(token_id) {
-            return Ok(*v);
+            return Ok(v);
         }
         let endpoint = format!("{}{}", self.host, GET_FEE_RATE);
         let mut params = std::collections::HashMap::new();
@@ -565,11 +867,11 @@ impl ClobClient {
             data: None,
             params: Some(params),
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let fee = val
             .get("base_fee")
             .and_then(|v| v.as_f64())
-            .ok_or(ClobError::Other("invalid fee response".to_string()))?;
+            .ok_or_else(|| ClobError::decode(&endpoint, "invalid fee response", val.to_string()))?;
         self.fee_rates.insert(token_id.to_string(), fee);
         Ok(fee)
     }
@@ -584,110 +886,78 @@ impl ClobClient {
     }
 
     pub async fn get_api_keys(&self) -> Result<Vec<crate::types::ApiKeyCreds>, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let endpoint = format!("{}{}", self.host, GET_API_KEYS);
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-        let headers = crate::headers::create_l2_headers(
-            signer_ref,
-            self.creds.as_ref().unwrap(),
-            "GET",
-            GET_API_KEYS,
-            None,
-            ts,
-        )
-        .await?;
-        let resp: crate::types::ApiKeysResponse = crate::http_helpers::get_typed(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: None,
-            }),
-        )
-        .await?;
+        let ts = self.auth_timestamp().await?;
+        let headers =
+            crate::headers::create_l2_headers(signer_ref, &creds, "GET", GET_API_KEYS, None, ts)
+                .await?;
+        let resp: crate::types::ApiKeysResponse = self
+            .get_typed(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(resp.api_keys)
     }
 
     pub async fn get_closed_only_mode(&self) -> Result<crate::types::BanStatus, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let endpoint = format!("{}{}", self.host, CLOSED_ONLY);
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-        let headers = crate::headers::create_l2_headers(
-            signer_ref,
-            self.creds.as_ref().unwrap(),
-            "GET",
-            CLOSED_ONLY,
-            None,
-            ts,
-        )
-        .await?;
-        let resp: crate::types::BanStatus = crate::http_helpers::get_typed(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: None,
-            }),
-        )
-        .await?;
+        let ts = self.auth_timestamp().await?;
+        let headers =
+            crate::headers::create_l2_headers(signer_ref, &creds, "GET", CLOSED_ONLY, None, ts)
+                .await?;
+        let resp: crate::types::BanStatus = self
+            .get_typed(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(resp)
     }
 
     pub async fn delete_api_key(&self) -> Result<(), ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        self.delete_api_key_with(&creds).await
+    }
+
+    async fn delete_api_key_with(&self, creds: &ApiKeyCreds) -> Result<(), ClobError> {
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let endpoint = format!("{}{}", self.host, DELETE_API_KEY);
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            creds,
             "DELETE",
             DELETE_API_KEY,
             None,
             ts,
         )
         .await?;
-        let _val: () = crate::http_helpers::del_typed::<(), Value>(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: None,
-            }),
-        )
-        .await?;
+        let _val: () = self
+            .del_typed::<(), Value>(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(())
     }
 
@@ -697,28 +967,13 @@ impl ClobClient {
         only_first_page: bool,
         next_cursor: Option<String>,
     ) -> Result<Vec<Value>, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-        let headers = crate::headers::create_l2_headers(
-            signer_ref,
-            self.creds.as_ref().unwrap(),
-            "GET",
-            GET_TRADES,
-            None,
-            ts,
-        )
-        .await?;
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
+        let headers =
+            crate::headers::create_l2_headers(signer_ref, &creds, "GET", GET_TRADES, None, ts)
+                .await?;
         let mut results: Vec<Value> = vec![];
         let mut cursor = next_cursor.unwrap_or_else(|| INITIAL_CURSOR.to_string());
         while cursor != END_CURSOR {
@@ -727,15 +982,16 @@ impl ClobClient {
             }
             let mut p = params.clone().unwrap_or_default();
             p.insert("next_cursor".to_string(), cursor.clone());
-            let val = get(
-                &format!("{}{}", self.host, GET_TRADES),
-                Some(RequestOptions {
-                    headers: Some(headers.clone()),
-                    data: None,
-                    params: Some(p),
-                }),
-            )
-            .await?;
+            let val = self
+                .get(
+                    &format!("{}{}", self.host, GET_TRADES),
+                    Some(RequestOptions {
+                        headers: Some(headers.clone()),
+                        data: None,
+                        params: Some(p),
+                    }),
+                )
+                .await?;
             let data = val
                 .get("data")
                 .and_then(|v| v.as_array())
@@ -765,48 +1021,39 @@ impl ClobClient {
             .await?;
         let mut trades: Vec<Trade> = Vec::new();
         for v in vals {
-            let t: Trade =
-                serde_json::from_value(v).map_err(|e| ClobError::Other(e.to_string()))?;
+            let t: Trade = decode_value(GET_TRADES, v)?;
             trades.push(t);
         }
         Ok(trades)
     }
 
     pub async fn get_notifications(&self) -> Result<Vec<Notification>, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let endpoint = format!("{}{}", self.host, GET_NOTIFICATIONS);
         let mut params = std::collections::HashMap::new();
         params.insert("signature_type".to_string(), "EOA".to_string());
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "GET",
             GET_NOTIFICATIONS,
             None,
             ts,
         )
         .await?;
-        let resp: MaybeVec<Notification> = crate::http_helpers::get_typed(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: Some(params),
-            }),
-        )
-        .await?;
+        let resp: MaybeVec<Notification> = self
+            .get_typed(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: Some(params),
+                }),
+            )
+            .await?;
         Ok(resp.into_vec())
     }
 
@@ -817,39 +1064,31 @@ impl ClobClient {
     }
 
     pub async fn drop_notifications(&self, ids: Option<&Vec<String>>) -> Result<(), ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let endpoint = format!("{}{}", self.host, DROP_NOTIFICATIONS);
         let params = crate::http_helpers::parse_drop_notification_params(ids);
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "DELETE",
             DROP_NOTIFICATIONS,
             None,
             ts,
         )
         .await?;
-        let _raw: SuccessResp = crate::http_helpers::del_typed(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: Some(params),
-            }),
-        )
-        .await?;
+        let _raw: SuccessResp = self
+            .del_typed(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: Some(params),
+                }),
+            )
+            .await?;
         Ok(())
     }
 
@@ -857,37 +1096,29 @@ impl ClobClient {
         &self,
         params: Option<std::collections::HashMap<String, String>>,
     ) -> Result<crate::types::BalanceAllowanceResponse, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "GET",
             GET_BALANCE_ALLOWANCE,
             None,
             ts,
         )
         .await?;
-        let resp: crate::types::BalanceAllowanceResponse = crate::http_helpers::get_typed(
-            &format!("{}{}", self.host, GET_BALANCE_ALLOWANCE),
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params,
-            }),
-        )
-        .await?;
+        let resp: crate::types::BalanceAllowanceResponse = self
+            .get_typed(
+                &format!("{}{}", self.host, GET_BALANCE_ALLOWANCE),
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params,
+                }),
+            )
+            .await?;
         Ok(resp)
     }
 
@@ -895,22 +1126,13 @@ impl ClobClient {
         &self,
         params: Option<std::collections::HashMap<String, String>>,
     ) -> Result<(), ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "GET",
             UPDATE_BALANCE_ALLOWANCE,
             None,
@@ -918,15 +1140,16 @@ impl ClobClient {
         )
         .await?;
         // API returns no useful body for update; call and ignore body
-        let _resp: OkResp = crate::http_helpers::get_typed(
-            &format!("{}{}", self.host, UPDATE_BALANCE_ALLOWANCE),
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params,
-            }),
-        )
-        .await?;
+        let _resp: OkResp = self
+            .get_typed(
+                &format!("{}{}", self.host, UPDATE_BALANCE_ALLOWANCE),
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params,
+                }),
+            )
+            .await?;
         Ok(())
     }
 
@@ -936,15 +1159,16 @@ impl ClobClient {
         &self,
         params: Option<std::collections::HashMap<String, String>>,
     ) -> Result<Vec<Reward>, ClobError> {
-        let val = crate::http_helpers::get(
-            &format!("{}{}", self.host, GET_EARNINGS_FOR_USER_FOR_DAY),
-            Some(RequestOptions {
-                headers: None,
-                data: None,
-                params,
-            }),
-        )
-        .await?;
+        let val = self
+            .get(
+                &format!("{}{}", self.host, GET_EARNINGS_FOR_USER_FOR_DAY),
+                Some(RequestOptions {
+                    headers: None,
+                    data: None,
+                    params,
+                }),
+            )
+            .await?;
 
         let arr = if let Some(a) = val.get("data").and_then(|v| v.as_array()) {
             a.clone()
@@ -955,82 +1179,147 @@ impl ClobClient {
         };
         let mut out: Vec<Reward> = Vec::new();
         for v in arr {
-            let r: Reward =
-                serde_json::from_value(v).map_err(|e| ClobError::Other(e.to_string()))?;
+            let r: Reward = decode_value(GET_EARNINGS_FOR_USER_FOR_DAY, v)?;
             out.push(r);
         }
         Ok(out)
     }
 
+    /// Build and sign a limit order. Tick size, neg-risk flag and fee rate are looked up (and
+    /// cached) per token; `options_tick`, `builder_config.tick_size` / `neg_risk` and
+    /// `user_order.fee_rate_bps` act as overrides and are checked against the market.
     pub async fn create_order(
         &self,
         user_order: UserOrder,
         options_tick: Option<&str>,
+    ) -> Result<SignedOrder, ClobError> {
+        self.build_limit_order(user_order, options_tick, None).await
+    }
+
+    // `order_type` is the type the order will be posted with, when known.
+    async fn build_limit_order(
+        &self,
+        mut user_order: UserOrder,
+        options_tick: Option<&str>,
+        order_type: Option<&OrderType>,
     ) -> Result<SignedOrder, ClobError> {
         // L1 auth required
         self.can_l1_auth()?;
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let token_id = user_order.token_id.clone();
+        let tick = self.resolve_tick_size(&token_id, options_tick).await?;
+        let min_size = self.get_min_order_size(&token_id).await?;
+        validate_order(&user_order, order_type, &tick, Some(min_size))?;
+        user_order.fee_rate_bps = Some(
+            self.resolve_fee_rate(&token_id, user_order.fee_rate_bps)
+                .await?,
+        );
+        // 按 token 的 neg_risk 选择标准或 negRisk 交易所
+        let neg_risk = self.resolve_neg_risk(&token_id).await?;
+        let exchange_addr = self.resolve_exchange_address(neg_risk)?;
+
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let ob = if let Some(cfg) = &self.builder_config {
             OrderBuilder::with_config(signer_ref, self.chain_id, cfg)
         } else {
             OrderBuilder::new(signer_ref, self.chain_id, None, None)
         };
-        // 动态解析合约地址：根据 chainId 与 neg_risk(token) 选择标准或 negRisk 交易所
-        let exchange_addr = self.resolve_exchange_address(&user_order.token_id);
-        let signed = ob
-            .build_order(&exchange_addr, &user_order, options_tick.unwrap_or("0.01"))
-            .await?;
-        Ok(signed)
+        ob.sign_order(&exchange_addr, &user_order, &tick).await
     }
 
+    /// Build and sign a market order; market parameters are resolved like in `create_order`.
     pub async fn create_market_order(
         &self,
-        user_market_order: UserMarketOrder,
+        mut user_market_order: UserMarketOrder,
         options_tick: Option<&str>,
     ) -> Result<SignedOrder, ClobError> {
         self.can_l1_auth()?;
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let token_id = user_market_order.token_id.clone();
+        let tick = self.resolve_tick_size(&token_id, options_tick).await?;
+        let min_size = self.get_min_order_size(&token_id).await?;
+        validate_market_order(&user_market_order, &tick, Some(min_size))?;
+        user_market_order.fee_rate_bps = Some(
+            self.resolve_fee_rate(&token_id, user_market_order.fee_rate_bps)
+                .await?,
+        );
+        let neg_risk = self.resolve_neg_risk(&token_id).await?;
+        let exchange_addr = self.resolve_exchange_address(neg_risk)?;
+
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let ob = if let Some(cfg) = &self.builder_config {
             OrderBuilder::with_config(signer_ref, self.chain_id, cfg)
         } else {
             OrderBuilder::new(signer_ref, self.chain_id, None, None)
         };
-        let exchange_addr = self.resolve_exchange_address(&user_market_order.token_id);
-        let signed = ob
-            .build_market_order(
-                &exchange_addr,
-                &user_market_order,
-                options_tick.unwrap_or("0.01"),
-            )
-            .await?;
-        Ok(signed)
+        ob.sign_market_order(&exchange_addr, &user_market_order, &tick)
+            .await
     }
 
-    /// 依据链 ID 与 builder_config.neg_risk 选择 verifyingContract 地址。
-    /// 注意：当前实现不主动查询 token neg_risk，只使用调用方传入的配置；如需自动检测可在上层先调用 get_neg_risk 并写入 builder_config。
-    fn resolve_exchange_address(&self, _token_id: &str) -> String {
-        let neg = self
-            .builder_config
-            .as_ref()
-            .and_then(|c| c.neg_risk)
-            .unwrap_or(false);
-        match (self.chain_id, neg) {
-            (137, false) => "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_string(),
-            (137, true) => "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_string(),
-            (80002, false) => "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40".to_string(),
-            (80002, true) => "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_string(),
-            (_other, _) => "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_string(),
+    /// Tick size used for an order: the override (`options_tick`, then
+    /// `builder_config.tick_size`) if any, otherwise the market minimum. Overrides finer than
+    /// the market minimum are rejected.
+    async fn resolve_tick_size(
+        &self,
+        token_id: &str,
+        options_tick: Option<&str>,
+    ) -> Result<String, ClobError> {
+        let min_tick = self.get_tick_size(token_id).await?;
+        let requested = options_tick.map(str::to_string).or_else(|| {
+            self.builder_config
+                .as_ref()
+                .and_then(|c| c.tick_size.clone())
+        });
+        match requested {
+            Some(tick) if is_tick_size_smaller(&tick, &min_tick) => {
+                Err(ClobError::Validation(format!(
+                    "invalid tick size ({}), minimum for the market is {}",
+                    tick, min_tick
+                )))
+            }
+            Some(tick) => Ok(tick),
+            None => Ok(min_tick),
+        }
+    }
+
+    /// The (cached) market flag. A `builder_config.neg_risk` that disagrees with it would sign
+    /// against the wrong exchange and is rejected.
+    async fn resolve_neg_risk(&self, token_id: &str) -> Result<bool, ClobError> {
+        let market = self.get_neg_risk(token_id).await?;
+        match self.builder_config.as_ref().and_then(|c| c.neg_risk) {
+            Some(neg_risk) if neg_risk != market => Err(ClobError::Validation(format!(
+                "invalid neg risk override ({}), neg risk for the market is {}",
+                neg_risk, market
+            ))),
+            _ => Ok(market),
+        }
+    }
+
+    /// Fee rate (bps) to sign: the market base fee, or the user value when the market charges
+    /// none. A user value that disagrees with a non-zero market fee is rejected.
+    async fn resolve_fee_rate(
+        &self,
+        token_id: &str,
+        user_fee: Option<f64>,
+    ) -> Result<f64, ClobError> {
+        let market_fee = self.get_fee_rate(token_id).await?;
+        match user_fee {
+            Some(fee) if market_fee > 0.0 && fee != market_fee => {
+                Err(ClobError::Validation(format!(
+                    "invalid user provided fee rate ({}), fee rate for the market must be {}",
+                    fee, market_fee
+                )))
+            }
+            Some(fee) if market_fee == 0.0 => Ok(fee),
+            _ => Ok(market_fee),
         }
     }
 
+    /// 依据链的 ContractConfig 与 token 的 neg_risk 选择 verifyingContract 地址。
+    fn resolve_exchange_address(&self, neg_risk: bool) -> Result<String, ClobError> {
+        Ok(self.contracts()?.exchange_for(neg_risk).to_string())
+    }
+
     // removed unused builder-auth helpers; builder headers are injected inline where needed
 
     pub async fn post_orders(
@@ -1039,24 +1328,15 @@ impl ClobClient {
         _defer_exec: bool,
     ) -> Result<Vec<Order>, ClobError> {
         // strong typed version returning parsed orders
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let body_str = serde_json::to_string(&args).map_err(|e| ClobError::Other(e.to_string()))?;
-        // 为了与 TypeScript SDK 保持完全一致的时间戳使用策略，这里在开启 use_server_time 时获取服务器时间用于 L2 HMAC
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        // 开启 use_server_time 时使用缓存的服务器时钟偏移作为 L2 HMAC 时间戳
+        let ts = self.auth_timestamp().await?;
         let mut headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "POST",
             POST_ORDERS,
             Some(&body_str),
@@ -1065,20 +1345,26 @@ impl ClobClient {
         .await?;
         if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("POST", POST_ORDERS, Some(&body_str), None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload(
+                    "POST",
+                    POST_ORDERS,
+                    Some(&body_str),
+                    ts.map(|t| t as i64),
+                )
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
         let endpoint = format!("{}{}", self.host, POST_ORDERS);
-        let raw: MaybeVec<Order> = crate::http_helpers::post_typed(
-            &endpoint,
-            Some(RequestOptions {
-                headers: Some(headers),
-                data: Some(args),
-                params: None,
-            }),
-        )
-        .await?;
+        let raw: MaybeVec<Order> = self
+            .post_typed(
+                &endpoint,
+                Some(RequestOptions {
+                    headers: Some(headers),
+                    data: Some(args),
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(raw.into_vec())
     }
 
@@ -1091,18 +1377,13 @@ impl ClobClient {
         defer_exec: bool,
     ) -> Result<OrderResponse, ClobError> {
         // build headers and post, then parse into Order
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
 
         // IMPORTANT: Use API key as owner, NOT wallet address
         // This matches TypeScript SDK behavior: orderToJson(order, this.creds?.key || "", ...)
-        let owner = self.creds.as_ref().unwrap().key.clone();
+        let owner = creds.key.clone();
 
         // Convert SignedOrder to NewOrder format
         let new_order =
@@ -1111,14 +1392,10 @@ impl ClobClient {
         let body_str =
             serde_json::to_string(&new_order).map_err(|e| ClobError::Other(e.to_string()))?;
         // 使用服务器时间保证与服务器 HMAC 计算节奏一致
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let mut headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "POST",
             POST_ORDER,
             Some(&body_str),
@@ -1128,8 +1405,13 @@ impl ClobClient {
         // Inject builder headers if builder auth configured
         if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("POST", POST_ORDER, Some(&body_str), None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload(
+                    "POST",
+                    POST_ORDER,
+                    Some(&body_str),
+                    ts.map(|t| t as i64),
+                )
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
         let endpoint = format!("{}{}", self.host, POST_ORDER);
@@ -1138,8 +1420,7 @@ impl ClobClient {
             data: Some(new_order),
             params: None,
         };
-        let res: MaybeItem<OrderResponse> =
-            crate::http_helpers::post_typed(&endpoint, Some(opts)).await?;
+        let res: MaybeItem<OrderResponse> = self.post_typed(&endpoint, Some(opts)).await?;
         Ok(res.into_item())
     }
 
@@ -1158,80 +1439,51 @@ impl ClobClient {
     /// Convenience: create (build & sign) then immediately post a limit order.
     /// orderType defaults to GTC (Good Till Cancelled).
     pub async fn create_and_post_order(
-        &mut self,
+        &self,
         user_order: UserOrder,
         options_tick: Option<&str>,
         order_type: Option<OrderType>,
     ) -> Result<OrderResponse, ClobError> {
-        // 避免在创建阶段发起额外 HTTP: 优先使用调用方提供的 tick 或 builder_config 中的 tick，否则使用默认值
-        let tick = if let Some(t) = options_tick {
-            t.to_string()
-        } else if let Some(cfg_tick) = self
-            .builder_config
-            .as_ref()
-            .and_then(|c| c.tick_size.as_ref())
-        {
-            cfg_tick.clone()
-        } else {
-            "0.01".to_string()
-        };
-        let signed = self.create_order(user_order, Some(&tick)).await?;
         let order_type = order_type.unwrap_or(OrderType::GTC);
+        let signed = self
+            .build_limit_order(user_order, options_tick, Some(&order_type))
+            .await?;
         self.post_signed_order(&signed, order_type, false).await
     }
 
     /// Convenience: create (build & sign) then immediately post a market order.
-    /// orderType defaults to FOK (Fill Or Kill).
+    /// orderType defaults to the order's own `order_type` (FOK or FAK).
     pub async fn create_and_post_market_order(
-        &mut self,
-        user_market_order: UserMarketOrder,
+        &self,
+        mut user_market_order: UserMarketOrder,
         options_tick: Option<&str>,
         order_type: Option<OrderType>,
     ) -> Result<OrderResponse, ClobError> {
-        // 避免在创建阶段发起额外 HTTP: 同上
-        let tick = if let Some(t) = options_tick {
-            t.to_string()
-        } else if let Some(cfg_tick) = self
-            .builder_config
-            .as_ref()
-            .and_then(|c| c.tick_size.as_ref())
-        {
-            cfg_tick.clone()
-        } else {
-            "0.01".to_string()
-        };
+        if let Some(t) = order_type {
+            user_market_order.order_type = t;
+        }
+        let order_type = user_market_order.order_type.clone();
         let signed = self
-            .create_market_order(user_market_order, Some(&tick))
+            .create_market_order(user_market_order, options_tick)
             .await?;
-        let order_type = order_type.unwrap_or(OrderType::FOK);
         self.post_signed_order(&signed, order_type, false).await
     }
 
-    // 已移除内部 tick size 解析逻辑（resolve_tick/get_tick_size_uncached）以避免隐式网络请求；保留显式 get_tick_size API。
     /// Helper: accept typed SignedOrder list and post them to POST_ORDERS endpoint.
     pub async fn post_orders_typed(
         &self,
         orders: Vec<SignedOrder>,
         _defer_exec: bool,
     ) -> Result<Value, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let body_str =
             serde_json::to_string(&orders).map_err(|e| ClobError::Other(e.to_string()))?;
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "POST",
             POST_ORDERS,
             Some(&body_str),
@@ -1240,22 +1492,28 @@ impl ClobClient {
         .await?;
         let headers = if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("POST", POST_ORDERS, Some(&body_str), None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload(
+                    "POST",
+                    POST_ORDERS,
+                    Some(&body_str),
+                    ts.map(|t| t as i64),
+                )
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             crate::headers::inject_builder_headers(headers, &b_payload)
         } else {
             headers
         };
         let endpoint = format!("{}{}", self.host, POST_ORDERS);
-        let res = crate::http_helpers::post_typed::<Value, Vec<SignedOrder>>(
-            &endpoint,
-            Some(RequestOptions {
-                headers: Some(headers),
-                data: Some(orders),
-                params: None,
-            }),
-        )
-        .await?;
+        let res = self
+            .post_typed::<Value, Vec<SignedOrder>>(
+                &endpoint,
+                Some(RequestOptions {
+                    headers: Some(headers),
+                    data: Some(orders),
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(res)
     }
 
@@ -1268,24 +1526,15 @@ impl ClobClient {
         _defer_exec: bool,
     ) -> Result<Vec<Order>, ClobError> {
         // Build body and headers similarly to post_orders_typed, but use the typed http helper
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let body_str =
             serde_json::to_string(&orders).map_err(|e| ClobError::Other(e.to_string()))?;
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "POST",
             POST_ORDERS,
             Some(&body_str),
@@ -1294,64 +1543,56 @@ impl ClobClient {
         .await?;
         let headers = if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("POST", POST_ORDERS, Some(&body_str), None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload(
+                    "POST",
+                    POST_ORDERS,
+                    Some(&body_str),
+                    ts.map(|t| t as i64),
+                )
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             crate::headers::inject_builder_headers(headers, &b_payload)
         } else {
             headers
         };
         let endpoint = format!("{}{}", self.host, POST_ORDERS);
-        let raw: MaybeVec<Order> = crate::http_helpers::post_typed(
-            &endpoint,
-            Some(RequestOptions {
-                headers: Some(headers),
-                data: Some(orders),
-                params: None,
-            }),
-        )
-        .await?;
+        let raw: MaybeVec<Order> = self
+            .post_typed(
+                &endpoint,
+                Some(RequestOptions {
+                    headers: Some(headers),
+                    data: Some(orders),
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(raw.into_vec())
     }
 
     pub async fn cancel_all(&self) -> Result<Vec<Order>, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-        let mut headers = crate::headers::create_l2_headers(
-            signer_ref,
-            self.creds.as_ref().unwrap(),
-            "DELETE",
-            CANCEL_ALL,
-            None,
-            ts,
-        )
-        .await?;
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
+        let mut headers =
+            crate::headers::create_l2_headers(signer_ref, &creds, "DELETE", CANCEL_ALL, None, ts)
+                .await?;
         if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("DELETE", CANCEL_ALL, None, None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload("DELETE", CANCEL_ALL, None, ts.map(|t| t as i64))
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
         let endpoint = format!("{}{}", self.host, CANCEL_ALL);
-        let raw: MaybeVec<Order> = crate::http_helpers::del_typed(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: None,
-            }),
-        )
-        .await?;
+        let raw: MaybeVec<Order> = self
+            .del_typed(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(raw.into_vec())
     }
 
@@ -1359,24 +1600,15 @@ impl ClobClient {
         &self,
         order_ids: Vec<String>,
     ) -> Result<Vec<Order>, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let body_str =
             serde_json::to_string(&order_ids).map_err(|e| ClobError::Other(e.to_string()))?;
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let mut headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "DELETE",
             CANCEL_MARKET_ORDERS,
             Some(&body_str),
@@ -1389,21 +1621,22 @@ impl ClobClient {
                     "DELETE",
                     CANCEL_MARKET_ORDERS,
                     Some(&body_str),
-                    None,
+                    ts.map(|t| t as i64),
                 )
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
         let endpoint = format!("{}{}", self.host, CANCEL_MARKET_ORDERS);
-        let raw: MaybeVec<Order> = crate::http_helpers::del_typed(
-            &endpoint,
-            Some(RequestOptions {
-                headers: Some(headers),
-                data: Some(order_ids),
-                params: None,
-            }),
-        )
-        .await?;
+        let raw: MaybeVec<Order> = self
+            .del_typed(
+                &endpoint,
+                Some(RequestOptions {
+                    headers: Some(headers),
+                    data: Some(order_ids),
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(raw.into_vec())
     }
 
@@ -1411,42 +1644,35 @@ impl ClobClient {
         &self,
         params: Option<std::collections::HashMap<String, String>>,
     ) -> Result<crate::types::OrderScoring, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let mut headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "GET",
             IS_ORDER_SCORING,
             None,
-            if self.use_server_time {
-                Some(self.get_server_time().await?)
-            } else {
-                None
-            },
+            ts,
         )
         .await?;
         if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("GET", IS_ORDER_SCORING, None, None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload("GET", IS_ORDER_SCORING, None, ts.map(|t| t as i64))
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
-        let resp: crate::types::OrderScoring = crate::http_helpers::get_typed(
-            &format!("{}{}", self.host, IS_ORDER_SCORING),
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params,
-            }),
-        )
-        .await?;
+        let resp: crate::types::OrderScoring = self
+            .get_typed(
+                &format!("{}{}", self.host, IS_ORDER_SCORING),
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params,
+                }),
+            )
+            .await?;
         Ok(resp)
     }
 
@@ -1454,24 +1680,15 @@ impl ClobClient {
         &self,
         order_ids: Option<Vec<String>>,
     ) -> Result<crate::types::OrdersScoring, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let body_str =
             serde_json::to_string(&order_ids).map_err(|e| ClobError::Other(e.to_string()))?;
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let mut headers = crate::headers::create_l2_headers(
             signer_ref,
-            self.creds.as_ref().unwrap(),
+            &creds,
             "POST",
             ARE_ORDERS_SCORING,
             Some(&body_str),
@@ -1480,54 +1697,45 @@ impl ClobClient {
         .await?;
         if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("POST", ARE_ORDERS_SCORING, Some(&body_str), None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload(
+                    "POST",
+                    ARE_ORDERS_SCORING,
+                    Some(&body_str),
+                    ts.map(|t| t as i64),
+                )
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
         // Serialize optional ids to JSON Value (null or array)
         let body_json =
             serde_json::to_value(&order_ids).map_err(|e| ClobError::Other(e.to_string()))?;
-        let resp: crate::types::OrdersScoring = crate::http_helpers::post_typed(
-            &format!("{}{}", self.host, ARE_ORDERS_SCORING),
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: Some(body_json),
-                params: None,
-            }),
-        )
-        .await?;
+        let resp: crate::types::OrdersScoring = self
+            .post_typed(
+                &format!("{}{}", self.host, ARE_ORDERS_SCORING),
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: Some(body_json),
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(resp)
     }
 
     pub async fn cancel_order(&self, order_id: &str) -> Result<Order, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let creds = self.creds.as_ref().unwrap();
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let mut params = std::collections::HashMap::new();
         params.insert("order_id".to_string(), order_id.to_string());
-        let mut headers = crate::headers::create_l2_headers(
-            signer_ref,
-            creds,
-            "DELETE",
-            CANCEL_ORDER,
-            None,
-            if self.use_server_time {
-                Some(self.get_server_time().await?)
-            } else {
-                None
-            },
-        )
-        .await?;
+        let ts = self.auth_timestamp().await?;
+        let mut headers =
+            crate::headers::create_l2_headers(signer_ref, &creds, "DELETE", CANCEL_ORDER, None, ts)
+                .await?;
         if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("DELETE", CANCEL_ORDER, None, None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload("DELETE", CANCEL_ORDER, None, ts.map(|t| t as i64))
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
         let endpoint = format!("{}{}", self.host, CANCEL_ORDER);
@@ -1536,31 +1744,21 @@ impl ClobClient {
             data: None,
             params: Some(params),
         };
-        let raw: MaybeItem<Order> = crate::http_helpers::del_typed(&endpoint, Some(opts)).await?;
+        let raw: MaybeItem<Order> = self.del_typed(&endpoint, Some(opts)).await?;
         let o = raw.into_item();
         Ok(o)
     }
 
     pub async fn cancel_orders(&self, order_ids: Vec<String>) -> Result<Vec<Order>, ClobError> {
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let creds = self.creds.as_ref().unwrap();
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         let body_str =
             serde_json::to_string(&order_ids).map_err(|e| ClobError::Other(e.to_string()))?;
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let ts = self.auth_timestamp().await?;
         let mut headers = crate::headers::create_l2_headers(
             signer_ref,
-            creds,
+            &creds,
             "POST",
             CANCEL_ORDERS,
             Some(&body_str),
@@ -1569,8 +1767,13 @@ impl ClobClient {
         .await?;
         if let Some(b) = &self.builder_signer {
             let b_payload = b
-                .create_builder_header_payload("POST", CANCEL_ORDERS, Some(&body_str), None)
-                .map_err(|e| ClobError::Other(format!("builder header error: {}", e)))?;
+                .create_builder_header_payload(
+                    "POST",
+                    CANCEL_ORDERS,
+                    Some(&body_str),
+                    ts.map(|t| t as i64),
+                )
+                .map_err(|e| ClobError::Signing(format!("builder header error: {}", e)))?;
             headers = crate::headers::inject_builder_headers(headers, &b_payload);
         }
         let endpoint = format!("{}{}", self.host, CANCEL_ORDERS);
@@ -1579,7 +1782,7 @@ impl ClobClient {
             data: Some(order_ids),
             params: None,
         };
-        let raw: MaybeVec<Order> = crate::http_helpers::post_typed(&endpoint, Some(opts)).await?;
+        let raw: MaybeVec<Order> = self.post_typed(&endpoint, Some(opts)).await?;
         Ok(raw.into_vec())
     }
 
@@ -1591,49 +1794,34 @@ impl ClobClient {
     /// Typed variant: try to deserialize an order response into `OpenOrder`.
     pub async fn get_order_typed(&self, order_id: &str) -> Result<OpenOrder, ClobError> {
         // TS SDK 行为：必须 L2 鉴权（canL2Auth + createL2Headers），useServerTime 时使用服务器时间戳参与 HMAC
-        if self.creds.is_none() {
-            return Err(ClobError::Other("L2 creds required".to_string()));
-        }
-        let signer_arc = self
-            .signer
-            .as_ref()
-            .ok_or(ClobError::Other("L1 signer required".to_string()))?;
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let creds = self.l2_creds().await?;
+        let signer_arc = self.signer.as_ref().ok_or(ClobError::L1AuthUnavailable)?;
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
         // requestPath 需要包含具体 /orders/{id}，与 TS 保持完全一致
         let request_path = format!("{}{}", GET_ORDER, order_id);
         let endpoint = format!("{}{}", self.host, request_path);
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
-        let headers = crate::headers::create_l2_headers(
-            signer_ref,
-            self.creds.as_ref().unwrap(),
-            "GET",
-            &request_path,
-            None,
-            ts,
-        )
-        .await?;
+        let ts = self.auth_timestamp().await?;
+        let headers =
+            crate::headers::create_l2_headers(signer_ref, &creds, "GET", &request_path, None, ts)
+                .await?;
         let opts = RequestOptions {
             headers: Some(headers),
             data: None,
             params: None,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         // API may return object or { data: object }
         if val.is_object() && val.get("id").is_some() {
-            let o: OpenOrder =
-                serde_json::from_value(val).map_err(|e| ClobError::Other(e.to_string()))?;
+            let o: OpenOrder = decode_value(&endpoint, val)?;
             Ok(o)
         } else if let Some(d) = val.get("data") {
-            let o: OpenOrder =
-                serde_json::from_value(d.clone()).map_err(|e| ClobError::Other(e.to_string()))?;
+            let o: OpenOrder = decode_value(&endpoint, d.clone())?;
             Ok(o)
         } else {
-            Err(ClobError::Other(
-                "unexpected order response shape".to_string(),
+            Err(ClobError::decode(
+                &endpoint,
+                "unexpected order response shape",
+                val.to_string(),
             ))
         }
     }
@@ -1657,19 +1845,20 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         // API might return { data: [...] } or an array directly
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected open orders response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected open orders response shape",
+                val.to_string(),
             ));
         };
-        let orders: Vec<SignedOrder> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let orders: Vec<SignedOrder> = decode_value(&endpoint, arr)?;
         Ok(orders)
     }
 
@@ -1683,19 +1872,20 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         // API may return an array or an object containing `data: [...]`
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected markets response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected markets response shape",
+                val.to_string(),
             ));
         };
-        let markets: Vec<crate::types::Market> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let markets: Vec<crate::types::Market> = decode_value(&endpoint, arr)?;
         Ok(markets)
     }
 
@@ -1710,19 +1900,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         // Try direct deserialization into MarketSummary or unwrap `data` field
         if val.get("market").is_some() {
-            let m: crate::types::MarketSummary =
-                serde_json::from_value(val).map_err(|e| ClobError::Other(e.to_string()))?;
+            let m: crate::types::MarketSummary = decode_value(&endpoint, val)?;
             Ok(m)
         } else if let Some(d) = val.get("data") {
-            let m: crate::types::MarketSummary =
-                serde_json::from_value(d.clone()).map_err(|e| ClobError::Other(e.to_string()))?;
+            let m: crate::types::MarketSummary = decode_value(&endpoint, d.clone())?;
             Ok(m)
         } else {
-            Err(ClobError::Other(
-                "unexpected market response shape".to_string(),
+            Err(ClobError::decode(
+                &endpoint,
+                "unexpected market response shape",
+                val.to_string(),
             ))
         }
     }
@@ -1737,18 +1927,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected simplified markets response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected simplified markets response shape",
+                val.to_string(),
             ));
         };
-        let markets: Vec<crate::types::Market> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let markets: Vec<crate::types::Market> = decode_value(&endpoint, arr)?;
         Ok(markets)
     }
 
@@ -1762,44 +1953,93 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected sampling markets response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected sampling markets response shape",
+                val.to_string(),
             ));
         };
-        let markets: Vec<crate::types::Market> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let markets: Vec<crate::types::Market> = decode_value(&endpoint, arr)?;
         Ok(markets)
     }
 
     pub async fn get_server_time(&self) -> Result<u64, ClobError> {
         let endpoint = format!("{}{}", self.host, TIME);
-        let val = get(&endpoint, None).await?;
+        let val = self.get(&endpoint, None).await?;
         // Expect number or object with `time`
         if val.is_number() {
-            Ok(val
-                .as_u64()
-                .ok_or(ClobError::Other("invalid time value".to_string()))?)
+            Ok(val.as_u64().ok_or_else(|| {
+                ClobError::decode(&endpoint, "invalid time value", val.to_string())
+            })?)
         } else if val.get("time").is_some() {
-            Ok(val
-                .get("time")
-                .and_then(|v| v.as_u64())
-                .ok_or(ClobError::Other("invalid time value".to_string()))?)
+            Ok(val.get("time").and_then(|v| v.as_u64()).ok_or_else(|| {
+                ClobError::decode(&endpoint, "invalid time value", val.to_string())
+            })?)
         } else {
-            Err(ClobError::Other(
-                "unexpected server time response".to_string(),
+            Err(ClobError::decode(
+                &endpoint,
+                "unexpected server time response",
+                val.to_string(),
             ))
         }
     }
 
+    /// Measure the offset to the server clock with one `/time` request and cache it.
+    /// Returns the offset in milliseconds (server minus local).
+    pub async fn sync_server_time(&self) -> Result<i64, ClobError> {
+        let sent = local_millis();
+        let server = self.get_server_time().await?;
+        self.server_clock.record(server, sent, local_millis());
+        Ok(self.server_clock.offset_ms().unwrap_or_default())
+    }
+
+    /// Timestamp for L1/L2 and builder auth headers: `None` (local time) unless `use_server_time`
+    /// is set, otherwise local time corrected by the cached offset, re-measured once it is stale.
+    async fn auth_timestamp(&self) -> Result<Option<u64>, ClobError> {
+        if !self.use_server_time {
+            return Ok(None);
+        }
+        if let Some(now) = self.server_clock.now() {
+            return Ok(Some(now));
+        }
+        self.sync_server_time().await?;
+        Ok(Some(self.server_clock.now().ok_or_else(|| {
+            ClobError::Other("server clock unavailable".to_string())
+        })?))
+    }
+
+    pub fn server_clock(&self) -> &ServerClock {
+        &self.server_clock
+    }
+
+    /// Re-measure the clock offset after `max_age` instead of the default 5 minutes.
+    pub fn with_clock_refresh(mut self, max_age: Duration) -> Self {
+        self.server_clock = ServerClock::new(max_age);
+        self
+    }
+
+    /// Keep the clock offset fresh from a background task so authenticated calls never wait
+    /// on `/time`. Failed syncs are retried on the next tick. Abort the handle to stop.
+    pub fn spawn_clock_sync(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let client = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let _ = client.sync_server_time().await;
+            }
+        })
+    }
+
     fn can_l1_auth(&self) -> Result<(), ClobError> {
         if self.signer.is_none() {
-            return Err(ClobError::Other("L1 auth required".to_string()));
+            return Err(ClobError::L1AuthUnavailable);
         }
         Ok(())
     }
@@ -1807,12 +2047,8 @@ impl ClobClient {
     pub async fn create_api_key(&self, nonce: Option<u64>) -> Result<ApiKeyCreds, ClobError> {
         self.can_l1_auth()?;
         let signer_arc = self.signer.as_ref().unwrap();
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let headers =
             crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, nonce, ts).await?;
         let endpoint = format!("{}{}", self.host, CREATE_API_KEY);
@@ -1821,10 +2057,9 @@ impl ClobClient {
             data: None,
             params: None,
         };
-        let val = post(&endpoint, Some(opts)).await?;
+        let val = self.post(&endpoint, Some(opts)).await?;
         // Deserialize into ApiKeyRaw then to ApiKeyCreds
-        let api_raw: ApiKeyRaw =
-            serde_json::from_value(val).map_err(|e| ClobError::Other(e.to_string()))?;
+        let api_raw: ApiKeyRaw = decode_value(&endpoint, val)?;
         let api_key = ApiKeyCreds {
             key: api_raw.api_key,
             secret: api_raw.secret,
@@ -1837,27 +2072,35 @@ impl ClobClient {
     pub async fn derive_api_key(
         &self,
         params: Option<std::collections::HashMap<String, String>>,
+    ) -> Result<ApiKeyCreds, ClobError> {
+        self.derive_api_key_with(None, params).await
+    }
+
+    /// Derive the API key created with `nonce` (TS `deriveApiKey(nonce)`).
+    pub async fn derive_api_key_with_nonce(&self, nonce: u64) -> Result<ApiKeyCreds, ClobError> {
+        self.derive_api_key_with(Some(nonce), None).await
+    }
+
+    async fn derive_api_key_with(
+        &self,
+        nonce: Option<u64>,
+        params: Option<std::collections::HashMap<String, String>>,
     ) -> Result<ApiKeyCreds, ClobError> {
         self.can_l1_auth()?;
         let signer_arc = self.signer.as_ref().unwrap();
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
-        let ts = if self.use_server_time {
-            Some(self.get_server_time().await?)
-        } else {
-            None
-        };
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let headers =
-            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, None, ts).await?;
+            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, nonce, ts).await?;
         let endpoint = format!("{}{}", self.host, DERIVE_API_KEY);
         let opts = RequestOptions {
             headers: Some(headers),
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         // Deserialize ApiKeyRaw then map to ApiKeyCreds
-        let api_raw: ApiKeyRaw =
-            serde_json::from_value(val).map_err(|e| ClobError::Other(e.to_string()))?;
+        let api_raw: ApiKeyRaw = decode_value(&endpoint, val)?;
         let api_key = ApiKeyCreds {
             key: api_raw.api_key,
             secret: api_raw.secret,
@@ -1866,62 +2109,150 @@ impl ClobClient {
         Ok(api_key)
     }
 
+    /// Create the API key for `nonce`, or derive it when the server already has one
+    /// (TS `createOrDeriveApiKey`).
+    pub async fn create_or_derive_api_key(
+        &self,
+        nonce: Option<u64>,
+    ) -> Result<ApiKeyCreds, ClobError> {
+        match self.create_api_key(nonce).await {
+            Ok(creds) if !creds.key.is_empty() => Ok(creds),
+            // 创建失败（key 已存在）时服务端返回 4xx，改用 derive；鉴权失败、限流、5xx 与网络错误直接返回
+            Ok(_) => self.derive_api_key_with(nonce, None).await,
+            Err(e) => match e.status() {
+                Some(status) if (400..500).contains(&status) && status != 401 && status != 429 => {
+                    self.derive_api_key_with(nonce, None).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Replace the current API key with a new one created for `nonce` (not used before): the
+    /// new key is persisted through the credential sink, every clone switches over to it, then
+    /// the old key is deleted. If the sink fails the new key is deleted again (best effort) and
+    /// the client keeps the old one. When deleting the old key fails the client already uses
+    /// the new key and the error is returned so the old key can be removed later.
+    pub async fn rotate_api_key(&self, nonce: u64) -> Result<ApiKeyCreds, ClobError> {
+        let old = self.l2_creds().await?;
+        let new = self.create_api_key(Some(nonce)).await?;
+        if new.key.is_empty() || new.key == old.key {
+            return Err(ClobError::Other(format!(
+                "api key rotation with nonce {} returned no new key",
+                nonce
+            )));
+        }
+        let stored = match &self.credential_sink {
+            Some(sink) => sink.store(&new).await,
+            None => Ok(()),
+        };
+        if let Err(e) = stored {
+            // an unsaved key could not be used after a restart; do not leave it live
+            if let Err(del) = self.delete_api_key_with(&new).await {
+                tracing::warn!(error = %del, "failed to delete unsaved rotated api key");
+            }
+            return Err(e);
+        }
+        self.set_creds(Some(new.clone()));
+        self.delete_api_key_with(&old).await?;
+        Ok(new)
+    }
+
+    /// L2 credentials for an authenticated call. With `with_auto_api_key`, missing credentials
+    /// are loaded from the credential sink or created / derived from the signer once and
+    /// shared by all clones.
+    async fn l2_creds(&self) -> Result<ApiKeyCreds, ClobError> {
+        if let Some(creds) = self.creds() {
+            return Ok(creds);
+        }
+        if !self.auto_api_key {
+            return Err(ClobError::L2AuthNotAvailable);
+        }
+        // 并发的首次调用只做一次 bootstrap
+        let _guard = self.creds_bootstrap.lock().await;
+        if let Some(creds) = self.creds() {
+            return Ok(creds);
+        }
+        let stored = match &self.credential_sink {
+            Some(sink) => sink.load().await?,
+            None => None,
+        };
+        let creds = match stored {
+            Some(creds) => creds,
+            None => {
+                let creds = self.create_or_derive_api_key(None).await?;
+                if let Some(sink) = &self.credential_sink {
+                    sink.store(&creds).await?;
+                }
+                creds
+            }
+        };
+        self.set_creds(Some(creds.clone()));
+        Ok(creds)
+    }
+
     pub async fn create_builder_api_key(&self) -> Result<crate::types::ApiKeyCreds, ClobError> {
         self.can_l1_auth()?;
         let signer_arc = self.signer.as_ref().unwrap();
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let headers =
-            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, None, None).await?;
+            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, None, ts).await?;
         let endpoint = format!("{}{}", self.host, CREATE_BUILDER_API_KEY);
-        let resp: crate::types::ApiKeyCreds = crate::http_helpers::post_typed(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: None,
-            }),
-        )
-        .await?;
+        let resp: crate::types::ApiKeyCreds = self
+            .post_typed(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(resp)
     }
 
     pub async fn get_builder_api_keys(&self) -> Result<Vec<crate::types::ApiKeyCreds>, ClobError> {
         self.can_l1_auth()?;
         let signer_arc = self.signer.as_ref().unwrap();
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let headers =
-            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, None, None).await?;
+            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, None, ts).await?;
         let endpoint = format!("{}{}", self.host, GET_BUILDER_API_KEYS);
-        let resp: crate::types::ApiKeysResponse = crate::http_helpers::get_typed(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: None,
-            }),
-        )
-        .await?;
+        let resp: crate::types::ApiKeysResponse = self
+            .get_typed(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: None,
+                }),
+            )
+            .await?;
         Ok(resp.api_keys)
     }
 
     pub async fn revoke_builder_api_key(&self, id: &str) -> Result<(), ClobError> {
         self.can_l1_auth()?;
         let signer_arc = self.signer.as_ref().unwrap();
-        let signer_ref: &EthersSigner = signer_arc.as_ref();
+        let signer_ref: &dyn AbstractSigner = signer_arc.as_ref();
+        let ts = self.auth_timestamp().await?;
         let headers =
-            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, None, None).await?;
+            crate::headers::create_l1_headers(signer_ref, self.chain_id as i32, None, ts).await?;
         let endpoint = format!("{}{}", self.host, REVOKE_BUILDER_API_KEY);
         let mut params = std::collections::HashMap::new();
         params.insert("id".to_string(), id.to_string());
-        let _val: () = crate::http_helpers::del_typed::<(), Value>(
-            &endpoint,
-            Some(RequestOptions::<Value> {
-                headers: Some(headers),
-                data: None,
-                params: Some(params),
-            }),
-        )
-        .await?;
+        let _val: () = self
+            .del_typed::<(), Value>(
+                &endpoint,
+                Some(RequestOptions::<Value> {
+                    headers: Some(headers),
+                    data: None,
+                    params: Some(params),
+                }),
+            )
+            .await?;
         Ok(())
     }
 
@@ -1935,18 +2266,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_object() && val.get("data").is_some() {
             val.get("data").cloned().unwrap_or_default()
         } else if val.is_array() {
             val
         } else {
-            return Err(ClobError::Other(
-                "unexpected sampling simplified markets response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected sampling simplified markets response shape",
+                val.to_string(),
             ));
         };
-        let markets: Vec<crate::types::Market> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let markets: Vec<crate::types::Market> = decode_value(&endpoint, arr)?;
         Ok(markets)
     }
 
@@ -1960,18 +2292,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected order books response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected order books response shape",
+                val.to_string(),
             ));
         };
-        let books: Vec<crate::types::OrderBookSummary> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let books: Vec<crate::types::OrderBookSummary> = decode_value(&endpoint, arr)?;
         Ok(books)
     }
 
@@ -1985,18 +2318,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected midpoint response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected midpoint response shape",
+                val.to_string(),
             ));
         };
-        let prices: Vec<crate::types::MarketPrice> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let prices: Vec<crate::types::MarketPrice> = decode_value(&endpoint, arr)?;
         Ok(prices)
     }
 
@@ -2010,18 +2344,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected midpoints response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected midpoints response shape",
+                val.to_string(),
             ));
         };
-        let prices: Vec<crate::types::MarketPrice> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let prices: Vec<crate::types::MarketPrice> = decode_value(&endpoint, arr)?;
         Ok(prices)
     }
 
@@ -2035,18 +2370,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected prices response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected prices response shape",
+                val.to_string(),
             ));
         };
-        let prices: Vec<crate::types::MarketPrice> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let prices: Vec<crate::types::MarketPrice> = decode_value(&endpoint, arr)?;
         Ok(prices)
     }
 
@@ -2060,18 +2396,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected spreads response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected spreads response shape",
+                val.to_string(),
             ));
         };
-        let prices: Vec<crate::types::MarketPrice> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let prices: Vec<crate::types::MarketPrice> = decode_value(&endpoint, arr)?;
         Ok(prices)
     }
 
@@ -2085,18 +2422,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected last trades prices response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected last trades prices response shape",
+                val.to_string(),
             ));
         };
-        let prices: Vec<crate::types::MarketPrice> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let prices: Vec<crate::types::MarketPrice> = decode_value(&endpoint, arr)?;
         Ok(prices)
     }
 
@@ -2110,19 +2448,20 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         // Accept array or { data: [...] }
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected prices history response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected prices history response shape",
+                val.to_string(),
             ));
         };
-        let prices: Vec<crate::types::MarketPrice> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let prices: Vec<crate::types::MarketPrice> = decode_value(&endpoint, arr)?;
         Ok(prices)
     }
 
@@ -2137,18 +2476,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected market trades events response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected market trades events response shape",
+                val.to_string(),
             ));
         };
-        let trades: Vec<crate::types::Trade> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let trades: Vec<crate::types::Trade> = decode_value(&endpoint, arr)?;
         Ok(trades)
     }
 
@@ -2163,18 +2503,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected earnings response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected earnings response shape",
+                val.to_string(),
             ));
         };
-        let rewards: Vec<crate::types::Reward> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let rewards: Vec<crate::types::Reward> = decode_value(&endpoint, arr)?;
         Ok(rewards)
     }
 
@@ -2188,18 +2529,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected total earnings response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected total earnings response shape",
+                val.to_string(),
             ));
         };
-        let rewards: Vec<crate::types::Reward> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let rewards: Vec<crate::types::Reward> = decode_value(&endpoint, arr)?;
         Ok(rewards)
     }
 
@@ -2222,19 +2564,20 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         // Accept object or { data: object }
         let obj = if val.is_object() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected liquidity percentages response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected liquidity percentages response shape",
+                val.to_string(),
             ));
         };
-        let map: std::collections::HashMap<String, f64> =
-            serde_json::from_value(obj).map_err(|e| ClobError::Other(e.to_string()))?;
+        let map: std::collections::HashMap<String, f64> = decode_value(&endpoint, obj)?;
         Ok(map)
     }
 
@@ -2257,18 +2600,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected rewards markets response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected rewards markets response shape",
+                val.to_string(),
             ));
         };
-        let rewards: Vec<crate::types::Reward> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let rewards: Vec<crate::types::Reward> = decode_value(&endpoint, arr)?;
         Ok(rewards)
     }
 
@@ -2283,18 +2627,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected rewards markets response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected rewards markets response shape",
+                val.to_string(),
             ));
         };
-        let rewards: Vec<crate::types::Reward> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let rewards: Vec<crate::types::Reward> = decode_value(&endpoint, arr)?;
         Ok(rewards)
     }
 
@@ -2318,18 +2663,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected rewards earnings percentages response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected rewards earnings percentages response shape",
+                val.to_string(),
             ));
         };
-        let rewards: Vec<crate::types::Reward> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let rewards: Vec<crate::types::Reward> = decode_value(&endpoint, arr)?;
         Ok(rewards)
     }
 
@@ -2352,18 +2698,19 @@ impl ClobClient {
             data: None,
             params,
         };
-        let val = get(&endpoint, Some(opts)).await?;
+        let val = self.get(&endpoint, Some(opts)).await?;
         let arr = if val.is_array() {
             val
         } else if let Some(d) = val.get("data") {
             d.clone()
         } else {
-            return Err(ClobError::Other(
-                "unexpected builder trades response shape".to_string(),
+            return Err(ClobError::decode(
+                &endpoint,
+                "unexpected builder trades response shape",
+                val.to_string(),
             ));
         };
-        let trades: Vec<crate::types::Trade> =
-            serde_json::from_value(arr).map_err(|e| ClobError::Other(e.to_string()))?;
+        let trades: Vec<crate::types::Trade> = decode_value(&endpoint, arr)?;
         Ok(trades)
     }
 
@@ -2393,3 +2740,415 @@ impl ClobClient {
         Ok(val)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::OrderViolation;
+    use crate::signer_adapter::EthersSigner;
+    use crate::transport::{HttpRequest, HttpResponse};
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// In-process fake exchange that records requests and answers from a fixed table.
+    #[derive(Default)]
+    struct FakeExchange {
+        seen: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[async_trait]
+    impl Transport for FakeExchange {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, ClobError> {
+            let (status, body) = if request.url.ends_with(GET_TICK_SIZE) {
+                (200, r#"{"minimum_tick_size":"0.001"}"#)
+            } else if request.url.ends_with(GET_NEG_RISK) {
+                (200, r#"{"neg_risk":true}"#)
+            } else if request.url.ends_with(GET_FEE_RATE) {
+                (200, r#"{"base_fee":0}"#)
+            } else if request.url.ends_with(TIME) {
+                (200, "1700000000")
+            } else if request.url.ends_with(GET_API_KEYS)
+                || (request.method == "GET" && request.url.ends_with(GET_BUILDER_API_KEYS))
+            {
+                (200, r#"{"apiKeys":[]}"#)
+            } else if request.method == "POST" && request.url.ends_with(CREATE_API_KEY) {
+                // nonce 0 is taken, nonce 500 hits a server error; any other nonce gets a fresh key
+                match request.headers.get("POLY_NONCE").map(String::as_str) {
+                    Some("0") | None => (400, r#"{"error":"Could not create api key"}"#),
+                    Some("500") => (500, r#"{"error":"internal error"}"#),
+                    _ => (
+                        200,
+                        r#"{"apiKey":"rotated","secret":"c2VjcmV0","passphrase":"pass"}"#,
+                    ),
+                }
+            } else if request.method == "DELETE" && request.url.ends_with(DELETE_API_KEY) {
+                (200, "null")
+            } else if request.method == "DELETE" && request.url.ends_with(CANCEL_ALL) {
+                (200, "[]")
+            } else if request.url.ends_with(DERIVE_API_KEY) {
+                (
+                    200,
+                    r#"{"apiKey":"derived","secret":"c2VjcmV0","passphrase":"pass"}"#,
+                )
+            } else if request.url.ends_with(CLOSED_ONLY) {
+                (401, r#"{"error":"invalid timestamp"}"#)
+            } else if request.url.ends_with(GET_ORDER_BOOK) {
+                (
+                    200,
+                    r#"{"market":"0xm","asset_id":"123","timestamp":"1","bids":[],"asks":[],"min_order_size":"5","tick_size":"0.001","neg_risk":true,"hash":""}"#,
+                )
+            } else {
+                (404, r#"{"error":"not found"}"#)
+            };
+            self.seen.lock().unwrap().push(request);
+            Ok(HttpResponse {
+                status,
+                headers: HashMap::new(),
+                body: body.to_string(),
+            })
+        }
+    }
+
+    fn signer() -> Arc<EthersSigner> {
+        Arc::new(
+            EthersSigner::new_from_private_key(
+                "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            )
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn requests_go_through_the_injected_transport() {
+        let fake = Arc::new(FakeExchange::default());
+        let client =
+            ClobClient::new("http://fake", 137, None, None, false).with_transport(fake.clone());
+
+        assert_eq!(client.get_tick_size("123").await.unwrap(), "0.001");
+        // second call is served from the tick size cache
+        assert_eq!(client.get_tick_size("123").await.unwrap(), "0.001");
+        assert!(client.get_market("abc", None).await.is_err());
+
+        let seen = fake.seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0].method, "GET");
+        assert_eq!(seen[0].url, "http://fake/tick-size");
+        assert_eq!(
+            seen[0].query,
+            vec![("token_id".to_string(), "123".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_order_resolves_and_checks_market_parameters() {
+        let fake = Arc::new(FakeExchange::default());
+        let client = ClobClient::new("http://fake", 137, Some(signer()), None, false)
+            .with_transport(fake.clone());
+        let order = |price: Decimal| UserOrder {
+            token_id: "123".to_string(),
+            price,
+            size: Decimal::from(10),
+            side: crate::types::Side::BUY,
+            fee_rate_bps: None,
+            nonce: None,
+            expiration: None,
+            taker: None,
+        };
+
+        let signed = client
+            .create_order(order(Decimal::new(505, 3)), None)
+            .await
+            .unwrap();
+        assert_eq!(signed.maker_amount, "5050000");
+        assert_eq!(signed.fee_rate_bps, "0");
+        assert_eq!(client.neg_risk.get("123"), Some(true));
+
+        // finer than the market tick, or a price outside [tick, 1 - tick]
+        let err = client
+            .create_order(order(Decimal::new(5, 1)), Some("0.0001"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClobError::Validation(m) if m.contains("invalid tick size")));
+        let mut bad = order(Decimal::new(4, 4));
+        bad.size = Decimal::from(2);
+        match client
+            .create_and_post_order(bad, None, Some(OrderType::FOK))
+            .await
+        {
+            Err(ClobError::InvalidOrder(e)) => assert_eq!(
+                e.violations,
+                vec![
+                    OrderViolation::PriceOutOfRange {
+                        price: Decimal::new(4, 4),
+                        min: Decimal::new(1, 3),
+                        max: Decimal::new(999, 3),
+                    },
+                    OrderViolation::PriceNotOnTick {
+                        price: Decimal::new(4, 4),
+                        tick_size: Decimal::new(1, 3),
+                    },
+                    OrderViolation::SizeBelowMinimum {
+                        size: Decimal::from(2),
+                        min_order_size: Decimal::from(5),
+                    },
+                    OrderViolation::ZeroAmount {
+                        maker_amount: "0".to_string(),
+                        taker_amount: "2000000".to_string(),
+                    },
+                    OrderViolation::MarketOnlyOrderType {
+                        order_type: OrderType::FOK,
+                    },
+                ]
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        // tick size, neg risk, fee rate and book were each fetched once; nothing was posted
+        assert_eq!(fake.seen.lock().unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn neg_risk_override_must_match_market() {
+        let fake = Arc::new(FakeExchange::default());
+        let client = ClobClient::new("http://fake", 137, Some(signer()), None, false)
+            .with_transport(fake.clone());
+        let order = UserOrder {
+            token_id: "123".to_string(),
+            price: Decimal::new(5, 1),
+            size: Decimal::from(10),
+            side: crate::types::Side::BUY,
+            fee_rate_bps: None,
+            nonce: None,
+            expiration: None,
+            taker: None,
+        };
+        let with_override = |neg_risk| {
+            client.clone().with_builder_config(ObBuilderConfig {
+                neg_risk: Some(neg_risk),
+                ..Default::default()
+            })
+        };
+
+        let err = with_override(false)
+            .create_order(order.clone(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClobError::Validation(m) if m.contains("invalid neg risk override")));
+        with_override(true).create_order(order, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn auth_headers_use_cached_server_clock() {
+        let fake = Arc::new(FakeExchange::default());
+        let creds = ApiKeyCreds {
+            key: "key".into(),
+            secret: "c2VjcmV0".into(),
+            passphrase: "pass".into(),
+        };
+        let client = ClobClient::new("http://fake", 137, Some(signer()), Some(creds), true)
+            .with_transport(fake.clone());
+        let time_requests = || {
+            fake.seen
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| r.url.ends_with(TIME))
+                .count()
+        };
+
+        client.get_api_keys().await.unwrap();
+        client.get_api_keys().await.unwrap();
+        assert_eq!(time_requests(), 1);
+        let ts = |i: usize| fake.seen.lock().unwrap()[i].headers["POLY_TIMESTAMP"].clone();
+        assert!(ts(1).parse::<u64>().unwrap() >= 1_700_000_000);
+        assert!(ts(1).parse::<u64>().unwrap() < 1_700_000_100);
+        // L1 headers for builder key calls use the same offset
+        client.get_builder_api_keys().await.unwrap();
+        assert_eq!(time_requests(), 1);
+        assert!(ts(3).parse::<u64>().unwrap() < 1_700_000_100);
+
+        // auth failure drops the offset; the next call measures it again
+        assert!(client.get_closed_only_mode().await.is_err());
+        assert!(client.server_clock().is_stale());
+        client.get_api_keys().await.unwrap();
+        assert_eq!(time_requests(), 2);
+    }
+
+    #[tokio::test]
+    async fn create_or_derive_falls_back_only_when_the_key_exists() {
+        let fake = Arc::new(FakeExchange::default());
+        let client = ClobClient::new("http://fake", 137, Some(signer()), None, false)
+            .with_transport(fake.clone());
+        let derives = || {
+            fake.seen
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| r.url.ends_with(DERIVE_API_KEY))
+                .count()
+        };
+
+        let derived = client.create_or_derive_api_key(Some(0)).await.unwrap();
+        assert_eq!(derived.key, "derived");
+        assert_eq!(derives(), 1);
+        let created = client.create_or_derive_api_key(Some(1)).await.unwrap();
+        assert_eq!(created.key, "rotated");
+
+        let err = client
+            .create_or_derive_api_key(Some(500))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(500));
+        assert_eq!(derives(), 1);
+    }
+
+    #[tokio::test]
+    async fn builder_headers_use_cached_server_clock() {
+        let fake = Arc::new(FakeExchange::default());
+        let creds = ApiKeyCreds {
+            key: "key".into(),
+            secret: "c2VjcmV0".into(),
+            passphrase: "pass".into(),
+        };
+        let client = ClobClient::new("http://fake", 137, Some(signer()), Some(creds), true)
+            .with_builder_signer("builder".into(), "c2VjcmV0".into(), "pass".into())
+            .with_transport(fake.clone());
+
+        client.cancel_all().await.unwrap();
+        let seen = fake.seen.lock().unwrap();
+        let cancel = seen.iter().find(|r| r.url.ends_with(CANCEL_ALL)).unwrap();
+        let builder_ts = &cancel.headers["POLY_BUILDER_TIMESTAMP"];
+        assert_eq!(builder_ts, &cancel.headers["POLY_TIMESTAMP"]);
+        assert!(builder_ts.parse::<u64>().unwrap() < 1_700_000_100);
+    }
+
+    #[derive(Default)]
+    struct MemorySink(Mutex<Vec<String>>);
+
+    #[async_trait]
+    impl CredentialSink for MemorySink {
+        async fn load(&self) -> Result<Option<ApiKeyCreds>, ClobError> {
+            Ok(None)
+        }
+
+        async fn store(&self, creds: &ApiKeyCreds) -> Result<(), ClobError> {
+            self.0.lock().unwrap().push(creds.key.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn bootstraps_l2_creds_on_first_use() {
+        let fake = Arc::new(FakeExchange::default());
+        let sink = Arc::new(MemorySink::default());
+        let client = ClobClient::new("http://fake", 137, Some(signer()), None, false)
+            .with_transport(fake.clone());
+        assert!(matches!(
+            client.get_api_keys().await,
+            Err(ClobError::L2AuthNotAvailable)
+        ));
+
+        let client = client
+            .with_auto_api_key(true)
+            .with_credential_sink(sink.clone());
+        let clone = client.clone();
+        client.get_api_keys().await.unwrap();
+        clone.get_api_keys().await.unwrap();
+
+        // create is refused (key exists), derive succeeds; done once for all clones
+        let paths: Vec<String> = fake
+            .seen
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| format!("{} {}", r.method, &r.url["http://fake".len()..]))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "POST /auth/api-key",
+                "GET /auth/derive-api-key",
+                "GET /auth/api-keys",
+                "GET /auth/api-keys",
+            ]
+        );
+        assert_eq!(
+            fake.seen.lock().unwrap()[2].headers["POLY_API_KEY"],
+            "derived"
+        );
+        assert_eq!(clone.creds().unwrap().key, "derived");
+        assert_eq!(*sink.0.lock().unwrap(), vec!["derived".to_string()]);
+    }
+
+    struct FailingSink;
+
+    #[async_trait]
+    impl CredentialSink for FailingSink {
+        async fn load(&self) -> Result<Option<ApiKeyCreds>, ClobError> {
+            Ok(None)
+        }
+
+        async fn store(&self, _creds: &ApiKeyCreds) -> Result<(), ClobError> {
+            Err(ClobError::Config("vault unavailable".into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn rotates_api_key() {
+        let old = ApiKeyCreds {
+            key: "old".into(),
+            secret: "c2VjcmV0".into(),
+            passphrase: "pass".into(),
+        };
+        let deleted = |fake: &FakeExchange| -> Vec<String> {
+            fake.seen
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| r.method == "DELETE")
+                .map(|r| r.headers["POLY_API_KEY"].clone())
+                .collect()
+        };
+
+        let fake = Arc::new(FakeExchange::default());
+        let sink = Arc::new(MemorySink::default());
+        let client = ClobClient::new("http://fake", 137, Some(signer()), Some(old.clone()), false)
+            .with_transport(fake.clone())
+            .with_credential_sink(sink.clone());
+        let clone = client.clone();
+        assert_eq!(client.rotate_api_key(1).await.unwrap().key, "rotated");
+        assert_eq!(clone.creds().unwrap().key, "rotated");
+        assert_eq!(*sink.0.lock().unwrap(), vec!["rotated".to_string()]);
+        assert_eq!(deleted(&fake), vec!["old".to_string()]);
+
+        // the new key could not be saved: it is deleted and the old one stays in use
+        let fake = Arc::new(FakeExchange::default());
+        let client = ClobClient::new("http://fake", 137, Some(signer()), Some(old), false)
+            .with_transport(fake.clone())
+            .with_credential_sink(Arc::new(FailingSink));
+        let err = client.rotate_api_key(1).await.unwrap_err();
+        assert!(matches!(err, ClobError::Config(m) if m.contains("vault")));
+        assert_eq!(client.creds().unwrap().key, "old");
+        assert_eq!(deleted(&fake), vec!["rotated".to_string()]);
+    }
+
+    #[test]
+    fn client_is_shareable() {
+        fn assert_shareable<T: Send + Sync + Clone + 'static>() {}
+        assert_shareable::<ClobClient>();
+
+        let client = ClobClient::new("http://fake", 137, None, None, false);
+        let clone = client.clone();
+        clone.apply_tick_size_change(&TickSizeChangeEvent {
+            asset_id: "1".into(),
+            market: "0xm".into(),
+            old_tick_size: "0.01".into(),
+            new_tick_size: "0.001".into(),
+            timestamp: String::new(),
+        });
+        assert_eq!(client.tick_sizes.get("1").as_deref(), Some("0.001"));
+        client.invalidate_market_metadata("1");
+        assert!(clone.tick_sizes.get("1").is_none());
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    offset_ms: i64,
+    taken_at: Instant,
+}
+
+/// Offset between the local clock and the CLOB server clock, used to timestamp L1/L2 auth
+/// headers without a `/time` request per call.
+///
+/// Clones share the same measurement.
+#[derive(Debug, Clone)]
+pub struct ServerClock {
+    sample: Arc<RwLock<Option<Sample>>>,
+    max_age: Duration,
+}
+
+impl Default for ServerClock {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(300))
+    }
+}
+
+pub(crate) fn local_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+impl ServerClock {
+    /// A measurement older than `max_age` is considered stale and re-measured on next use.
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            sample: Arc::new(RwLock::new(None)),
+            max_age,
+        }
+    }
+
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Record a `/time` answer (seconds). `sent_ms` / `received_ms` are the local clock around
+    /// the request; the server time is assumed to be taken halfway through.
+    pub fn record(&self, server_secs: u64, sent_ms: i64, received_ms: i64) {
+        let midpoint = sent_ms + (received_ms - sent_ms) / 2;
+        let sample = Sample {
+            offset_ms: server_secs as i64 * 1000 - midpoint,
+            taken_at: Instant::now(),
+        };
+        *self.sample.write().unwrap_or_else(|e| e.into_inner()) = Some(sample);
+    }
+
+    /// Measured offset (server minus local) in milliseconds, stale or not.
+    pub fn offset_ms(&self) -> Option<i64> {
+        self.read().map(|s| s.offset_ms)
+    }
+
+    /// Whether there is no measurement or it is older than `max_age`.
+    pub fn is_stale(&self) -> bool {
+        self.read()
+            .is_none_or(|s| s.taken_at.elapsed() >= self.max_age)
+    }
+
+    /// Current server time in seconds, if a fresh measurement exists.
+    pub fn now(&self) -> Option<u64> {
+        if self.is_stale() {
+            return None;
+        }
+        let offset = self.offset_ms()?;
+        Some(((local_millis() + offset).max(0) / 1000) as u64)
+    }
+
+    /// Drop the measurement, e.g. after an auth failure that may be caused by clock skew.
+    pub fn invalidate(&self) {
+        *self.sample.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    fn read(&self) -> Option<Sample> {
+        *self.sample.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_measured_offset() {
+        let clock = ServerClock::default();
+        assert!(clock.is_stale());
+        assert_eq!(clock.now(), None);
+
+        // server is 120s ahead; request took 200ms
+        let sent = local_millis();
+        clock.record((sent / 1000 + 120) as u64, sent, sent + 200);
+        let offset = clock.offset_ms().unwrap();
+        assert!((119_000..=120_000).contains(&offset), "offset {}", offset);
+        let now = clock.now().unwrap() as i64;
+        assert!((now - local_millis() / 1000 - 120).abs() <= 1);
+
+        clock.clone().invalidate();
+        assert!(clock.is_stale());
+        let expired = ServerClock::new(Duration::ZERO);
+        expired.record(1, 0, 0);
+        assert_eq!(expired.now(), None);
+    }
+}
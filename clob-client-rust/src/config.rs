@@ -0,0 +1,478 @@
+use crate::client::ClobClient;
+use crate::credentials::{CredentialSink, CredentialStore};
+use crate::errors::ClobError;
+use crate::order_builder::BuilderConfig;
+use crate::rate_limit::RateLimitConfig;
+use crate::retry::RetryPolicy;
+use crate::signer_adapter::{AbstractSigner, EthersSigner};
+use crate::transport::Transport;
+use crate::types::ApiKeyCreds;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const POLYGON: i64 = 137;
+pub const AMOY: i64 = 80002;
+
+pub const POLYGON_HOST: &str = "https://clob.polymarket.com";
+pub const AMOY_HOST: &str = "https://clob-staging.polymarket.com";
+
+/// Contract addresses used for signing and settlement on one chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractConfig {
+    /// CTF exchange (verifying contract of standard orders).
+    pub exchange: String,
+    /// Neg-risk CTF exchange (verifying contract of neg-risk orders).
+    pub neg_risk_exchange: String,
+    pub neg_risk_adapter: String,
+    /// Collateral token (USDC).
+    pub collateral: String,
+    /// Conditional tokens framework (ERC1155 outcome tokens).
+    pub conditional_tokens: String,
+}
+
+impl ContractConfig {
+    pub fn polygon() -> Self {
+        Self {
+            exchange: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_string(),
+            neg_risk_exchange: "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_string(),
+            neg_risk_adapter: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296".to_string(),
+            collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_string(),
+            conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_string(),
+        }
+    }
+
+    pub fn amoy() -> Self {
+        Self {
+            exchange: "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40".to_string(),
+            neg_risk_exchange: "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_string(),
+            neg_risk_adapter: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296".to_string(),
+            collateral: "0x9c4e1703476e875070ee25b56a58b008cfb8fa78".to_string(),
+            conditional_tokens: "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB".to_string(),
+        }
+    }
+
+    /// Preset for a known chain; other chains need an explicit config.
+    pub fn for_chain(chain_id: i64) -> Result<Self, ClobError> {
+        match chain_id {
+            POLYGON => Ok(Self::polygon()),
+            AMOY => Ok(Self::amoy()),
+            other => Err(ClobError::UnsupportedChain(other)),
+        }
+    }
+
+    /// Exchange that verifies orders of a standard or neg-risk market.
+    pub fn exchange_for(&self, neg_risk: bool) -> &str {
+        if neg_risk {
+            &self.neg_risk_exchange
+        } else {
+            &self.exchange
+        }
+    }
+}
+
+/// Default CLOB host of a known chain.
+pub fn default_host(chain_id: i64) -> Option<&'static str> {
+    match chain_id {
+        POLYGON => Some(POLYGON_HOST),
+        AMOY => Some(AMOY_HOST),
+        _ => None,
+    }
+}
+
+/// Serializable client settings, read from a JSON file or the environment. Every field is
+/// optional; missing ones fall back to the chain preset.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct ClobConfig {
+    pub host: Option<String>,
+    pub chain_id: Option<i64>,
+    pub use_server_time: Option<bool>,
+    pub private_key: Option<String>,
+    /// Encrypted JSON keystore used instead of `private_key`.
+    pub keystore: Option<PathBuf>,
+    pub keystore_password: Option<String>,
+    /// `CredentialStore` file holding the API and builder credentials.
+    pub credentials_file: Option<PathBuf>,
+    pub credentials_password: Option<String>,
+    pub creds: Option<ApiKeyCreds>,
+    /// Builder API credentials (secret base64 encoded).
+    pub builder_creds: Option<ApiKeyCreds>,
+    pub contracts: Option<ContractConfig>,
+}
+
+impl ClobConfig {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ClobError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| ClobError::Config(format!("read {}: {}", path.display(), e)))?;
+        serde_json::from_str(&text)
+            .map_err(|e| ClobError::Config(format!("parse {}: {}", path.display(), e)))
+    }
+
+    /// Read the variables used by the examples: `CLOB_API_URL`, `CHAIN_ID`, `PK`,
+    /// `CLOB_API_KEY` / `CLOB_SECRET` / `CLOB_PASS_PHRASE`, `BUILDER_API_KEY` /
+    /// `BUILDER_SECRET_B64` / `BUILDER_PASSPHRASE` and `CLOB_USE_SERVER_TIME`, plus
+    /// `PK_KEYSTORE` / `PK_KEYSTORE_PASSWORD` and `CLOB_CREDENTIALS_FILE` /
+    /// `CLOB_CREDENTIALS_PASSWORD` for encrypted files.
+    pub fn from_env() -> Result<Self, ClobError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let creds = |key: &str, secret: &str, passphrase: &str| match (
+            var(key),
+            var(secret),
+            var(passphrase),
+        ) {
+            (Some(key), Some(secret), Some(passphrase)) => Some(ApiKeyCreds {
+                key,
+                secret,
+                passphrase,
+            }),
+            _ => None,
+        };
+        let chain_id = var("CHAIN_ID")
+            .map(|v| {
+                v.parse::<i64>()
+                    .map_err(|e| ClobError::Config(format!("invalid CHAIN_ID {:?}: {}", v, e)))
+            })
+            .transpose()?;
+        let use_server_time = var("CLOB_USE_SERVER_TIME")
+            .map(|v| {
+                v.parse::<bool>().map_err(|e| {
+                    ClobError::Config(format!("invalid CLOB_USE_SERVER_TIME {:?}: {}", v, e))
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            host: var("CLOB_API_URL"),
+            chain_id,
+            use_server_time,
+            private_key: var("PK"),
+            keystore: var("PK_KEYSTORE").map(PathBuf::from),
+            keystore_password: var("PK_KEYSTORE_PASSWORD"),
+            credentials_file: var("CLOB_CREDENTIALS_FILE").map(PathBuf::from),
+            credentials_password: var("CLOB_CREDENTIALS_PASSWORD"),
+            creds: creds("CLOB_API_KEY", "CLOB_SECRET", "CLOB_PASS_PHRASE"),
+            builder_creds: creds(
+                "BUILDER_API_KEY",
+                "BUILDER_SECRET_B64",
+                "BUILDER_PASSPHRASE",
+            ),
+            contracts: None,
+        })
+    }
+}
+
+/// Fluent construction of a `ClobClient`.
+///
+/// ```no_run
+/// # fn demo() -> Result<(), clob_client_rust::errors::ClobError> {
+/// use clob_client_rust::config::ClobClientBuilder;
+///
+/// // mainnet preset, overridden by CLOB_API_URL / CHAIN_ID / PK / CLOB_API_KEY ... when set
+/// let client = ClobClientBuilder::polygon().with_env()?.with_server_time(true).build()?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone)]
+pub struct ClobClientBuilder {
+    chain_id: i64,
+    host: Option<String>,
+    contracts: Option<ContractConfig>,
+    signer: Option<Arc<dyn AbstractSigner>>,
+    creds: Option<ApiKeyCreds>,
+    builder_creds: Option<ApiKeyCreds>,
+    builder_config: Option<BuilderConfig>,
+    use_server_time: bool,
+    transport: Option<Arc<dyn Transport>>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limits: Option<RateLimitConfig>,
+    metadata_ttl: Option<Duration>,
+    auto_api_key: bool,
+    credential_sink: Option<Arc<dyn CredentialSink>>,
+}
+
+impl Default for ClobClientBuilder {
+    fn default() -> Self {
+        Self::new(POLYGON)
+    }
+}
+
+impl ClobClientBuilder {
+    /// Builder for `chain_id`; host and contracts default to the chain preset.
+    pub fn new(chain_id: i64) -> Self {
+        Self {
+            chain_id,
+            host: None,
+            contracts: None,
+            signer: None,
+            creds: None,
+            builder_creds: None,
+            builder_config: None,
+            use_server_time: false,
+            transport: None,
+            retry_policy: None,
+            rate_limits: None,
+            metadata_ttl: None,
+            auto_api_key: false,
+            credential_sink: None,
+        }
+    }
+
+    pub fn polygon() -> Self {
+        Self::new(POLYGON)
+    }
+
+    pub fn amoy() -> Self {
+        Self::new(AMOY)
+    }
+
+    /// Start from a JSON config file (see `ClobConfig`).
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ClobError> {
+        Self::default().with_config(ClobConfig::from_file(path)?)
+    }
+
+    /// Start from environment variables (see `ClobConfig::from_env`).
+    pub fn from_env() -> Result<Self, ClobError> {
+        Self::default().with_env()
+    }
+
+    /// Apply environment variables on top of the current settings.
+    pub fn with_env(self) -> Result<Self, ClobError> {
+        self.with_config(ClobConfig::from_env()?)
+    }
+
+    /// Apply the fields set in `config` on top of the current settings.
+    pub fn with_config(mut self, config: ClobConfig) -> Result<Self, ClobError> {
+        if let Some(chain_id) = config.chain_id {
+            self.chain_id = chain_id;
+        }
+        if let Some(host) = config.host {
+            self.host = Some(host);
+        }
+        if let Some(v) = config.use_server_time {
+            self.use_server_time = v;
+        }
+        if let Some(pk) = config.private_key {
+            self.signer = Some(Arc::new(EthersSigner::new_from_private_key(&pk)?));
+        }
+        if let Some(path) = config.keystore {
+            let password = config.keystore_password.ok_or_else(|| {
+                ClobError::Config("keystore set without keystore_password".to_string())
+            })?;
+            self = self.with_keystore(path, password)?;
+        }
+        if let Some(path) = config.credentials_file {
+            let password = config.credentials_password.ok_or_else(|| {
+                ClobError::Config("credentials_file set without credentials_password".to_string())
+            })?;
+            self = self.with_credential_store(&CredentialStore::new(path, password))?;
+        }
+        if config.creds.is_some() {
+            self.creds = config.creds;
+        }
+        if config.builder_creds.is_some() {
+            self.builder_creds = config.builder_creds;
+        }
+        if config.contracts.is_some() {
+            self.contracts = config.contracts;
+        }
+        Ok(self)
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// Override the contract addresses of the chain preset (required for other chains).
+    pub fn with_contracts(mut self, contracts: ContractConfig) -> Self {
+        self.contracts = Some(contracts);
+        self
+    }
+
+    /// Any `AbstractSigner` backend; the same `Arc` can be handed to `RelayClient`.
+    pub fn with_signer(mut self, signer: Arc<dyn AbstractSigner>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    pub fn with_private_key(mut self, hex_priv: &str) -> Result<Self, ClobError> {
+        self.signer = Some(Arc::new(EthersSigner::new_from_private_key(hex_priv)?));
+        Ok(self)
+    }
+
+    /// Signer from an encrypted JSON keystore (scrypt or pbkdf2).
+    pub fn with_keystore(
+        mut self,
+        path: impl AsRef<Path>,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Self, ClobError> {
+        self.signer = Some(Arc::new(EthersSigner::from_keystore(path, password)?));
+        Ok(self)
+    }
+
+    /// API and builder credentials saved in `store`, when present.
+    pub fn with_credential_store(mut self, store: &CredentialStore) -> Result<Self, ClobError> {
+        let stored = store.load()?;
+        if stored.api.is_some() {
+            self.creds = stored.api;
+        }
+        if let Some(b) = stored.builder {
+            self.builder_creds = Some(ApiKeyCreds {
+                key: b.key,
+                secret: b.secret,
+                passphrase: b.passphrase,
+            });
+        }
+        Ok(self)
+    }
+
+    pub fn with_creds(mut self, creds: ApiKeyCreds) -> Self {
+        self.creds = Some(creds);
+        self
+    }
+
+    /// Builder API credentials; the secret must be base64 encoded.
+    pub fn with_builder_creds(mut self, creds: ApiKeyCreds) -> Self {
+        self.builder_creds = Some(creds);
+        self
+    }
+
+    /// Create or derive the L2 credentials from the signer on first use when none were given.
+    pub fn with_auto_api_key(mut self, enabled: bool) -> Self {
+        self.auto_api_key = enabled;
+        self
+    }
+
+    /// Persist bootstrapped and rotated L2 credentials, e.g. `Arc::new(CredentialStore::new(..))`.
+    pub fn with_credential_sink(mut self, sink: Arc<dyn CredentialSink>) -> Self {
+        self.credential_sink = Some(sink);
+        self
+    }
+
+    pub fn with_builder_config(mut self, config: BuilderConfig) -> Self {
+        self.builder_config = Some(config);
+        self
+    }
+
+    pub fn with_server_time(mut self, use_server_time: bool) -> Self {
+        self.use_server_time = use_server_time;
+        self
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    pub fn with_rate_limits(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limits = Some(config);
+        self
+    }
+
+    pub fn with_metadata_ttl(mut self, ttl: Duration) -> Self {
+        self.metadata_ttl = Some(ttl);
+        self
+    }
+
+    /// Build the client. Fails for a chain without preset unless both host and contracts
+    /// were provided.
+    pub fn build(self) -> Result<ClobClient, ClobError> {
+        let contracts = match self.contracts {
+            Some(c) => c,
+            None => ContractConfig::for_chain(self.chain_id)?,
+        };
+        let host = match self.host {
+            Some(h) => h,
+            None => default_host(self.chain_id)
+                .ok_or(ClobError::UnsupportedChain(self.chain_id))?
+                .to_string(),
+        };
+        let mut client = ClobClient::new(
+            &host,
+            self.chain_id,
+            self.signer,
+            self.creds,
+            self.use_server_time,
+        )
+        .with_contracts(contracts)
+        .with_auto_api_key(self.auto_api_key);
+        if let Some(sink) = self.credential_sink {
+            client = client.with_credential_sink(sink);
+        }
+        if let Some(b) = self.builder_creds {
+            client = client.with_builder_signer(b.key, b.secret, b.passphrase);
+        }
+        if let Some(cfg) = self.builder_config {
+            client = client.with_builder_config(cfg);
+        }
+        if let Some(t) = self.transport {
+            client = client.with_transport(t);
+        }
+        if let Some(p) = self.retry_policy {
+            client = client.with_retry_policy(p);
+        }
+        if let Some(r) = self.rate_limits {
+            client = client.with_rate_limits(r);
+        }
+        if let Some(ttl) = self.metadata_ttl {
+            client = client.with_metadata_ttl(ttl);
+        }
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_overrides_and_unknown_chains() {
+        let client = ClobClientBuilder::amoy().build().unwrap();
+        assert_eq!(client.chain_id, AMOY);
+        assert_eq!(client.host, AMOY_HOST);
+        assert_eq!(
+            client.contracts().unwrap().exchange_for(false),
+            "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"
+        );
+
+        assert!(matches!(
+            ClobClientBuilder::new(1).build(),
+            Err(ClobError::UnsupportedChain(1))
+        ));
+        let mut contracts = ContractConfig::polygon();
+        contracts.exchange = "0x0000000000000000000000000000000000000001".into();
+        let client = ClobClientBuilder::new(31337)
+            .with_host("http://localhost:8080/")
+            .with_contracts(contracts.clone())
+            .build()
+            .unwrap();
+        assert_eq!(client.host, "http://localhost:8080");
+        assert_eq!(client.contracts().unwrap(), &contracts);
+
+        let path = std::env::temp_dir().join(format!("clob-config-{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"chain_id": 80002, "host": "http://staging", "use_server_time": true}"#,
+        )
+        .unwrap();
+        let client = ClobClientBuilder::from_file(&path)
+            .unwrap()
+            .build()
+            .unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(client.chain_id, AMOY);
+        assert_eq!(client.host, "http://staging");
+        assert!(client.use_server_time);
+        assert_eq!(client.contracts().unwrap(), &ContractConfig::amoy());
+    }
+}
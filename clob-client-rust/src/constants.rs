@@ -1,4 +1,4 @@
-pub const CREDS_CREATION_WARNING: &str = "🚨🚨🚨\nYour credentials CANNOT be recovered after they've been created. \nBe sure to store them safely!\n🚨🚨🚨";
+pub const CREDS_CREATION_WARNING: &str = "🚨🚨🚨\nYour credentials CANNOT be recovered after they've been created. \nBe sure to store them safely (e.g. in a CredentialStore)!\n🚨🚨🚨";
 
 pub const INITIAL_CURSOR: &str = "MA==";
 pub const END_CURSOR: &str = "LTE=";
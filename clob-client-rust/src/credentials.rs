@@ -0,0 +1,143 @@
+use crate::errors::ClobError;
+use crate::types::ApiKeyCreds;
+use async_trait::async_trait;
+use builder_signing_sdk_rs::{BuilderApiKeyCreds, EncryptedFile, KeystoreError};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Credentials kept in a `CredentialStore`.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct StoredCredentials {
+    /// L2 API credentials (`create_api_key` / `derive_api_key`).
+    pub api: Option<ApiKeyCreds>,
+    /// Builder API credentials (secret base64 encoded).
+    pub builder: Option<BuilderApiKeyCreds>,
+}
+
+/// Password-encrypted file (keystore v3 layout: scrypt + AES-128-CTR) holding API and builder
+/// credentials. Save newly created keys right away: the server cannot show them again.
+#[derive(Clone)]
+pub struct CredentialStore {
+    file: EncryptedFile,
+}
+
+fn store_error(path: &Path, e: KeystoreError) -> ClobError {
+    ClobError::Config(format!("credential store {}: {}", path.display(), e))
+}
+
+impl CredentialStore {
+    pub fn new(path: impl Into<PathBuf>, password: impl Into<String>) -> Self {
+        Self {
+            file: EncryptedFile::new(path, password),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        self.file.path()
+    }
+
+    pub fn exists(&self) -> bool {
+        self.file.exists()
+    }
+
+    /// Stored credentials; empty when the file does not exist yet.
+    pub fn load(&self) -> Result<StoredCredentials, ClobError> {
+        if !self.exists() {
+            return Ok(StoredCredentials::default());
+        }
+        self.file.load().map_err(|e| store_error(self.path(), e))
+    }
+
+    pub fn save(&self, creds: &StoredCredentials) -> Result<(), ClobError> {
+        self.file
+            .save(creds)
+            .map_err(|e| store_error(self.path(), e))
+    }
+
+    /// Replace the L2 credentials, keeping the builder ones.
+    pub fn save_api_creds(&self, creds: &ApiKeyCreds) -> Result<(), ClobError> {
+        let mut stored = self.load()?;
+        stored.api = Some(creds.clone());
+        self.save(&stored)
+    }
+
+    /// Replace the builder credentials, keeping the L2 ones.
+    pub fn save_builder_creds(&self, creds: &BuilderApiKeyCreds) -> Result<(), ClobError> {
+        let mut stored = self.load()?;
+        stored.builder = Some(creds.clone());
+        self.save(&stored)
+    }
+
+    // scrypt and file I/O block; keep them off the async runtime's worker threads
+    async fn blocking<T, F>(&self, f: F) -> Result<T, ClobError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&CredentialStore) -> Result<T, ClobError> + Send + 'static,
+    {
+        let store = self.clone();
+        tokio::task::spawn_blocking(move || f(&store))
+            .await
+            .map_err(|e| ClobError::Other(format!("credential store task failed: {}", e)))?
+    }
+}
+
+/// Persistence for L2 credentials created by the client itself (auto bootstrap with
+/// `with_auto_api_key`, `rotate_api_key`). Implement it to keep them in a vault or database.
+#[async_trait]
+pub trait CredentialSink: Send + Sync {
+    /// Credentials saved earlier, tried before creating or deriving new ones.
+    async fn load(&self) -> Result<Option<ApiKeyCreds>, ClobError>;
+
+    /// Called with new credentials before the client starts using them.
+    async fn store(&self, creds: &ApiKeyCreds) -> Result<(), ClobError>;
+}
+
+#[async_trait]
+impl CredentialSink for CredentialStore {
+    async fn load(&self) -> Result<Option<ApiKeyCreds>, ClobError> {
+        self.blocking(|store| Ok(store.load()?.api)).await
+    }
+
+    async fn store(&self, creds: &ApiKeyCreds) -> Result<(), ClobError> {
+        let creds = creds.clone();
+        self.blocking(move |store| store.save_api_creds(&creds))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_rejects_wrong_password() {
+        let path = std::env::temp_dir().join(format!("clob-creds-{}.json", std::process::id()));
+        let store = CredentialStore::new(&path, "correct horse");
+        assert!(store.load().unwrap().api.is_none());
+
+        store
+            .save_api_creds(&ApiKeyCreds {
+                key: "key".into(),
+                secret: "c2VjcmV0".into(),
+                passphrase: "pass".into(),
+            })
+            .unwrap();
+        store
+            .save_builder_creds(&BuilderApiKeyCreds {
+                key: "bkey".into(),
+                secret: "YnNlY3JldA==".into(),
+                passphrase: "bpass".into(),
+            })
+            .unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        let loaded = store.load();
+        let wrong = CredentialStore::new(&path, "wrong").load();
+        std::fs::remove_file(&path).ok();
+
+        assert!(!text.contains("c2VjcmV0"));
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.api.unwrap().key, "key");
+        assert_eq!(loaded.builder.unwrap().passphrase, "bpass");
+        assert!(matches!(wrong, Err(ClobError::Config(_))));
+    }
+}
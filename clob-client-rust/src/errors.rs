@@ -1,3 +1,5 @@
+use crate::types::OrderType;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -14,6 +16,190 @@ pub enum ClobError {
     #[error("Builder key auth failed!")]
     BuilderAuthFailed,
 
+    /// The request never produced an HTTP response (DNS, connect, TLS, timeout, body read).
+    #[error("transport error: {0}")]
+    Transport(String),
+
+    /// Non-2xx response. `message` is the API's `error` / `errorMsg` field when the body is JSON.
+    #[error("HTTP {status} error from {endpoint}: {}", message.as_deref().unwrap_or(body))]
+    Http {
+        status: u16,
+        endpoint: String,
+        message: Option<String>,
+        body: String,
+    },
+
+    /// 2xx response whose body did not match the expected shape.
+    #[error("failed to decode response from {endpoint}: {error}")]
+    Decode {
+        endpoint: String,
+        error: String,
+        body: String,
+    },
+
+    #[error("signing error: {0}")]
+    Signing(String),
+
+    #[error("validation error: {0}")]
+    Validation(String),
+
+    /// No contract preset for this chain; provide a `ContractConfig` explicitly.
+    #[error("unsupported chain id {0}")]
+    UnsupportedChain(i64),
+
+    /// Invalid client configuration (config file, environment variables).
+    #[error("config error: {0}")]
+    Config(String),
+
+    /// Order rejected by the pre-flight checks, before signing.
+    #[error(transparent)]
+    InvalidOrder(#[from] OrderValidationError),
+
     #[error("Other error: {0}")]
     Other(String),
 }
+
+/// One reason an order failed pre-flight validation.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum OrderViolation {
+    #[error("unsupported tick size {0}")]
+    UnsupportedTickSize(String),
+
+    #[error("price {price} outside [{min}, {max}]")]
+    PriceOutOfRange {
+        price: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+
+    #[error("price {price} is not a multiple of tick size {tick_size}")]
+    PriceNotOnTick { price: Decimal, tick_size: Decimal },
+
+    #[error("size {size} below market minimum {min_order_size}")]
+    SizeBelowMinimum {
+        size: Decimal,
+        min_order_size: Decimal,
+    },
+
+    #[error("amounts round to zero (maker {maker_amount}, taker {taker_amount})")]
+    ZeroAmount {
+        maker_amount: String,
+        taker_amount: String,
+    },
+
+    #[error("expiration {expiration} is not in the future (now {now})")]
+    ExpirationInPast { expiration: u64, now: u64 },
+
+    #[error("GTD orders need an expiration")]
+    MissingExpiration,
+
+    #[error("{order_type:?} is only valid for market orders")]
+    MarketOnlyOrderType { order_type: OrderType },
+
+    #[error("market orders must be FOK or FAK, got {order_type:?}")]
+    LimitOnlyOrderType { order_type: OrderType },
+}
+
+/// Every violation found for one order.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("invalid order: {}", .violations.iter().map(|v| v.to_string()).collect::<Vec<_>>().join("; "))]
+pub struct OrderValidationError {
+    pub violations: Vec<OrderViolation>,
+}
+
+impl OrderValidationError {
+    /// `Ok` when nothing was found, otherwise an error carrying every violation.
+    pub fn check(violations: Vec<OrderViolation>) -> Result<(), Self> {
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Self { violations })
+        }
+    }
+}
+
+impl From<builder_signing_sdk_rs::SignerError> for ClobError {
+    fn from(e: builder_signing_sdk_rs::SignerError) -> Self {
+        ClobError::Signing(e.to_string())
+    }
+}
+
+impl ClobError {
+    /// Build an `Http` error from a raw response, extracting the API error message if present.
+    pub fn http(status: u16, endpoint: impl Into<String>, body: impl Into<String>) -> Self {
+        let body = body.into();
+        let message = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|v| {
+                ["error", "errorMsg", "message"]
+                    .iter()
+                    .find_map(|k| v.get(*k).and_then(|m| m.as_str()).map(str::to_string))
+            });
+        ClobError::Http {
+            status,
+            endpoint: endpoint.into(),
+            message,
+            body,
+        }
+    }
+
+    pub fn decode(
+        endpoint: impl Into<String>,
+        error: impl std::fmt::Display,
+        body: impl Into<String>,
+    ) -> Self {
+        ClobError::Decode {
+            endpoint: endpoint.into(),
+            error: error.to_string(),
+            body: body.into(),
+        }
+    }
+
+    /// HTTP status code, for `Http` errors.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ClobError::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Error message returned by the API, for `Http` errors with a JSON body.
+    pub fn api_message(&self) -> Option<&str> {
+        match self {
+            ClobError::Http { message, .. } => message.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether repeating the same request may succeed: transport failures, 429 and 5xx.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ClobError::Transport(_) => true,
+            ClobError::Http { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_error_extracts_api_message_and_retryability() {
+        let e = ClobError::http(400, "/order", r#"{"errorMsg":"not enough balance"}"#);
+        assert_eq!(e.status(), Some(400));
+        assert_eq!(e.api_message(), Some("not enough balance"));
+        assert!(!e.is_retryable());
+        assert_eq!(
+            e.to_string(),
+            "HTTP 400 error from /order: not enough balance"
+        );
+
+        let e = ClobError::http(503, "/book", "upstream unavailable");
+        assert_eq!(e.api_message(), None);
+        assert!(e.is_retryable());
+        assert!(ClobError::Transport("connection reset".into()).is_retryable());
+        assert!(!ClobError::Validation("bad price".into()).is_retryable());
+    }
+}
@@ -1,7 +1,10 @@
 use crate::errors::ClobError;
 use crate::exchange_consts::{PROTOCOL_NAME, PROTOCOL_VERSION};
 use crate::signing::Eip712Signer;
-use crate::types::{OrderData, SignedOrder};
+use crate::types::{OrderData, Side, SignedOrder};
+use ethers::abi::{Token, encode};
+use ethers::core::types::transaction::eip712::{Eip712, TypedData};
+use ethers::core::types::{Address, H256, Signature, U256};
 use ethers::core::utils::keccak256;
 // rand::random used below; no Rng trait required
 use serde_json::{Value, json};
@@ -14,13 +17,102 @@ pub fn generate_order_salt() -> String {
     v.to_string()
 }
 
-pub struct ExchangeOrderBuilder<'a, S: Eip712Signer> {
+const EIP712_DOMAIN_TYPE: &str =
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+const ORDER_TYPE: &str = "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,uint256 feeRateBps,uint8 side,uint8 signatureType)";
+
+fn parse_address(field: &str, value: &str) -> Result<Address, ClobError> {
+    value
+        .parse()
+        .map_err(|_| ClobError::Validation(format!("invalid {} address {:?}", field, value)))
+}
+
+fn parse_uint(field: &str, value: &str) -> Result<U256, ClobError> {
+    U256::from_dec_str(value)
+        .map_err(|_| ClobError::Validation(format!("invalid {} {:?}", field, value)))
+}
+
+/// EIP-712 domain separator of the exchange contract at `exchange`.
+pub fn order_domain_separator(exchange: &str, chain_id: i64) -> Result<[u8; 32], ClobError> {
+    Ok(keccak256(encode(&[
+        Token::FixedBytes(keccak256(EIP712_DOMAIN_TYPE).to_vec()),
+        Token::FixedBytes(keccak256(PROTOCOL_NAME).to_vec()),
+        Token::FixedBytes(keccak256(PROTOCOL_VERSION).to_vec()),
+        Token::Uint(U256::from(chain_id)),
+        Token::Address(parse_address("exchange", exchange)?),
+    ])))
+}
+
+/// EIP-712 struct hash of the order (`hashStruct(Order)`); the signature is not part of it.
+pub fn order_struct_hash(order: &SignedOrder) -> Result<[u8; 32], ClobError> {
+    let side = match order.side {
+        Side::BUY => 0u8,
+        Side::SELL => 1u8,
+    };
+    Ok(keccak256(encode(&[
+        Token::FixedBytes(keccak256(ORDER_TYPE).to_vec()),
+        Token::Uint(parse_uint("salt", &order.salt)?),
+        Token::Address(parse_address("maker", &order.maker)?),
+        Token::Address(parse_address("signer", &order.signer)?),
+        Token::Address(parse_address("taker", &order.taker)?),
+        Token::Uint(parse_uint("tokenId", &order.token_id)?),
+        Token::Uint(parse_uint("makerAmount", &order.maker_amount)?),
+        Token::Uint(parse_uint("takerAmount", &order.taker_amount)?),
+        Token::Uint(parse_uint("expiration", &order.expiration)?),
+        Token::Uint(parse_uint("nonce", &order.nonce)?),
+        Token::Uint(parse_uint("feeRateBps", &order.fee_rate_bps)?),
+        Token::Uint(U256::from(side)),
+        Token::Uint(U256::from(u8::from(order.signature_type))),
+    ])))
+}
+
+fn order_digest(order: &SignedOrder, exchange: &str, chain_id: i64) -> Result<H256, ClobError> {
+    let mut buf = Vec::with_capacity(66);
+    buf.extend_from_slice(&[0x19, 0x01]);
+    buf.extend_from_slice(&order_domain_separator(exchange, chain_id)?);
+    buf.extend_from_slice(&order_struct_hash(order)?);
+    Ok(H256::from(keccak256(buf)))
+}
+
+/// Order hash as computed by the exchange contract (`hashOrder`): the EIP-712 digest that is
+/// signed, `0x`-prefixed. `exchange` is the CTF or neg risk exchange the order was signed for.
+pub fn order_hash(order: &SignedOrder, exchange: &str, chain_id: i64) -> Result<String, ClobError> {
+    Ok(format!("{:#x}", order_digest(order, exchange, chain_id)?))
+}
+
+/// Check that `order.signature` was produced by `order.signer` over the order hash. All
+/// signature types carry an ECDSA signature from the signer EOA (the maker may be a proxy or
+/// Safe), so this works offline without querying the chain.
+pub fn verify_order_signature(
+    order: &SignedOrder,
+    exchange: &str,
+    chain_id: i64,
+) -> Result<(), ClobError> {
+    let digest = order_digest(order, exchange, chain_id)?;
+    let signature: Signature = order
+        .signature
+        .parse()
+        .map_err(|e| ClobError::Validation(format!("invalid order signature: {}", e)))?;
+    let recovered = signature
+        .recover(digest)
+        .map_err(|e| ClobError::Validation(format!("invalid order signature: {}", e)))?;
+    let signer = parse_address("signer", &order.signer)?;
+    if recovered != signer {
+        return Err(ClobError::Validation(format!(
+            "order signed by {:#x}, expected signer {:#x}",
+            recovered, signer
+        )));
+    }
+    Ok(())
+}
+
+pub struct ExchangeOrderBuilder<'a, S: Eip712Signer + ?Sized> {
     contract_address: &'a str,
     chain_id: i64,
     signer: &'a S,
 }
 
-impl<'a, S: Eip712Signer> ExchangeOrderBuilder<'a, S> {
+impl<'a, S: Eip712Signer + ?Sized> ExchangeOrderBuilder<'a, S> {
     pub fn new(contract_address: &'a str, chain_id: i64, signer: &'a S) -> Self {
         Self {
             contract_address,
@@ -35,8 +127,8 @@ impl<'a, S: Eip712Signer> ExchangeOrderBuilder<'a, S> {
     ) -> Result<SignedOrder, ClobError> {
         let mut order = self.build_order(order_data)?;
         let order_typed = self.build_order_typed_data(&order);
-        // 调试: 输出 typed-data 关键域（仅在设置 CLOB_DEBUG_FULL 或 CLOB_DEBUG_TYPED 时）
-        if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_TYPED").is_ok() {
+        // 调试: 输出 typed-data 关键域（TRACE 级别）
+        if tracing::enabled!(tracing::Level::TRACE) {
             let domain = order_typed
                 .get("domain")
                 .cloned()
@@ -45,10 +137,12 @@ impl<'a, S: Eip712Signer> ExchangeOrderBuilder<'a, S> {
                 .get("message")
                 .cloned()
                 .unwrap_or(serde_json::Value::Null);
-            let hash_preview = self.build_order_hash(&order_typed);
-            eprintln!(
-                "[EIP712 DEBUG] domain={} message={} hash_preview={}",
-                domain, message, hash_preview
+            let hash_preview = self.build_order_hash(&order_typed)?;
+            tracing::trace!(
+                %domain,
+                %message,
+                %hash_preview,
+                "order typed data"
             );
         }
         let sig = self.build_order_signature(&order_typed).await?;
@@ -71,7 +165,7 @@ impl<'a, S: Eip712Signer> ExchangeOrderBuilder<'a, S> {
         let mut order = self.build_order(order_data.clone())?;
         order.salt = forced_salt.to_string();
         let order_typed = self.build_order_typed_data(&order);
-        if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_TYPED").is_ok() {
+        if tracing::enabled!(tracing::Level::TRACE) {
             let domain = order_typed
                 .get("domain")
                 .cloned()
@@ -80,10 +174,13 @@ impl<'a, S: Eip712Signer> ExchangeOrderBuilder<'a, S> {
                 .get("message")
                 .cloned()
                 .unwrap_or(serde_json::Value::Null);
-            let hash_preview = self.build_order_hash(&order_typed);
-            eprintln!(
-                "[EIP712 DEBUG] (forced_salt) domain={} message={} hash_preview={}",
-                domain, message, hash_preview
+            let hash_preview = self.build_order_hash(&order_typed)?;
+            tracing::trace!(
+                %domain,
+                %message,
+                %hash_preview,
+                forced_salt,
+                "order typed data"
             );
         }
         let sig = self.build_order_signature(&order_typed).await?;
@@ -117,7 +214,7 @@ impl<'a, S: Eip712Signer> ExchangeOrderBuilder<'a, S> {
             nonce: data.nonce.clone(),
             fee_rate_bps: data.fee_rate_bps.clone(),
             side: data.side.clone(),
-            signature_type: data.signature_type.clone(),
+            signature_type: data.signature_type,
             signature: String::new(),
         };
         Ok(order)
@@ -196,9 +293,78 @@ impl<'a, S: Eip712Signer> ExchangeOrderBuilder<'a, S> {
             .await
     }
 
-    pub fn build_order_hash(&self, typed: &Value) -> String {
-        let ser = serde_json::to_vec(typed).unwrap_or_default();
-        let hash = keccak256(&ser);
-        format!("0x{}", hex::encode(hash))
+    /// EIP-712 digest of `typed` (as built by `build_order_typed_data`), i.e. the order hash.
+    /// See also `order_hash`.
+    pub fn build_order_hash(&self, typed: &Value) -> Result<String, ClobError> {
+        let typed: TypedData = serde_json::from_value(typed.clone())
+            .map_err(|e| ClobError::Validation(format!("invalid order typed data: {}", e)))?;
+        let hash = typed
+            .encode_eip712()
+            .map_err(|e| ClobError::Validation(format!("invalid order typed data: {}", e)))?;
+        Ok(format!("0x{}", hex::encode(hash)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer_adapter::EthersSigner;
+    use crate::types::SignatureType;
+
+    #[tokio::test]
+    async fn order_hash_matches_signed_digest() {
+        let signer = EthersSigner::new_from_private_key(
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        )
+        .unwrap();
+        let exchange = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
+        let builder = ExchangeOrderBuilder::new(exchange, 137, &signer);
+        let address = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string();
+        let order = builder
+            .build_signed_order_with_salt(
+                OrderData {
+                    maker: address.clone(),
+                    taker: "0x0000000000000000000000000000000000000000".into(),
+                    token_id: "1234".into(),
+                    maker_amount: "5050000".into(),
+                    taker_amount: "10000000".into(),
+                    side: Side::BUY,
+                    fee_rate_bps: "0".into(),
+                    nonce: "0".into(),
+                    signer: address,
+                    expiration: "0".into(),
+                    signature_type: SignatureType::EOA,
+                },
+                "479249096354",
+            )
+            .await
+            .unwrap();
+
+        let hash = order_hash(&order, exchange, 137).unwrap();
+        assert_eq!(
+            hash,
+            builder
+                .build_order_hash(&builder.build_order_typed_data(&order))
+                .unwrap()
+        );
+        let mut typed = builder.build_order_typed_data(&order);
+        typed["message"]["makerAmount"] = Value::String("not a number".into());
+        assert!(matches!(
+            builder.build_order_hash(&typed),
+            Err(ClobError::Validation(_))
+        ));
+        verify_order_signature(&order, exchange, 137).unwrap();
+
+        // another exchange, chain or field gives another hash and fails verification
+        let neg_risk = "0xC5d563A36AE78145C45a50134d48A1215220f80a";
+        assert_ne!(order_hash(&order, neg_risk, 137).unwrap(), hash);
+        assert!(verify_order_signature(&order, neg_risk, 137).is_err());
+        assert!(verify_order_signature(&order, exchange, 80002).is_err());
+        let mut tampered = order.clone();
+        tampered.maker_amount = "5050001".into();
+        assert!(matches!(
+            verify_order_signature(&tampered, exchange, 137),
+            Err(ClobError::Validation(m)) if m.contains("expected signer")
+        ));
     }
 }
@@ -7,7 +7,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 pub type Headers = HashMap<String, String>;
 
 /// Build L1 headers using EIP-712 typed-data signing via the Eip712Signer trait.
-pub async fn create_l1_headers<S: crate::signing::Eip712Signer + Send + Sync>(
+pub async fn create_l1_headers<S: crate::signing::Eip712Signer + Send + Sync + ?Sized>(
     signer: &S,
     chain_id: i32,
     nonce: Option<u64>,
@@ -28,7 +28,7 @@ pub async fn create_l1_headers<S: crate::signing::Eip712Signer + Send + Sync>(
     // Use EIP-712 signing helper implemented in `signing.rs`.
     let sig = crate::signing::build_clob_eip712_signature(signer, chain_id as i64, ts, n)
         .await
-        .map_err(|e| ClobError::Other(format!("failed to build eip712 signature: {}", e)))?;
+        .map_err(|e| ClobError::Signing(format!("failed to build eip712 signature: {}", e)))?;
 
     let mut headers = Headers::new();
     headers.insert("POLY_ADDRESS".to_string(), address);
@@ -39,7 +39,7 @@ pub async fn create_l1_headers<S: crate::signing::Eip712Signer + Send + Sync>(
 }
 
 /// Build L2 headers using HMAC signature (POLY API HMAC) along with API key/passphrase.
-pub async fn create_l2_headers<S: crate::signing::Eip712Signer + Send + Sync>(
+pub async fn create_l2_headers<S: crate::signing::Eip712Signer + Send + Sync + ?Sized>(
     signer: &S,
     creds: &ApiKeyCreds,
     method: &str,
@@ -67,44 +67,22 @@ pub async fn create_l2_headers<S: crate::signing::Eip712Signer + Send + Sync>(
         Ok(s) => s,
         Err(e) => {
             // If HMAC calculation fails (e.g., secret not base64), return an error to surface configuration problems.
-            return Err(ClobError::Other(format!(
+            return Err(ClobError::Signing(format!(
                 "failed to build hmac signature: {}",
                 e
             )));
         }
     };
 
-    // 调试输出（受环境变量控制），避免在生产泄露敏感信息。
-    // 仅在设置 CLOB_DEBUG_FULL 或 CLOB_DEBUG_RAW 时输出。
-    if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-        let preview_secret = if creds.secret.len() > 6 {
-            format!("{}***", &creds.secret[..6])
-        } else {
-            "***".to_string()
-        };
-        let payload_preview = {
-            let mut base = format!("{}{}{}", ts, method, request_path);
-            if let Some(b) = body {
-                base.push_str(b);
-            }
-            // 避免日志过长，只截取前后 200 字符
-            if base.len() > 420 {
-                format!("{} ... {}", &base[..200], &base[base.len() - 200..])
-            } else {
-                base
-            }
-        };
-        eprintln!(
-            "[L2 DEBUG] ts(sec)={} method={} path={} secret(b64-prefix)={} sig={} payload_part={} body_len={}",
-            ts,
-            method,
-            request_path,
-            preview_secret,
-            sig,
-            payload_preview,
-            body.map(|b| b.len()).unwrap_or(0)
-        );
-    }
+    // 调试输出（TRACE 级别），不输出 secret，签名仅保留前缀。
+    tracing::trace!(
+        ts,
+        method,
+        request_path,
+        sig_prefix = sig.get(..12).unwrap_or("***"),
+        body_len = body.map(|b| b.len()).unwrap_or(0),
+        "built L2 headers"
+    );
 
     let mut headers = Headers::new();
     headers.insert("POLY_ADDRESS".to_string(), address);
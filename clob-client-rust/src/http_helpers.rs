@@ -1,9 +1,16 @@
 use crate::errors::ClobError;
+use crate::rate_limit::{EndpointGroup, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
 use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tracing::Instrument;
 
 pub const GET: &str = "GET";
 pub const POST: &str = "POST";
@@ -18,145 +25,154 @@ pub struct RequestOptions<B = Value> {
     pub params: Option<QueryParams>,
 }
 
-pub async fn post_typed<R, B>(
-    endpoint: &str,
-    options: Option<RequestOptions<B>>,
-) -> Result<R, ClobError>
-where
-    R: DeserializeOwned,
-    B: Serialize,
-{
-    let client = Client::new();
-    let mut req = client.post(endpoint);
-    let mut debug_headers: Option<std::collections::HashMap<String, String>> = None;
-    let mut debug_body: Option<String> = None;
-    let mut debug_params: Option<QueryParams> = None;
-    if let Some(opts) = options {
-        if let Some(h) = opts.headers {
-            // Capture headers for debug (with masking) before moving
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                let mut masked = std::collections::HashMap::new();
-                for (k, v) in h.iter() {
-                    let key = k.to_string();
-                    let val = if key.contains("PASSPHRASE") || key.contains("API_KEY") {
-                        if v.len() > 6 {
-                            format!("{}***", &v[..6])
-                        } else {
-                            "***".to_string()
-                        }
-                    } else if key.contains("SIGNATURE") {
-                        if v.len() > 12 {
-                            format!("{}...", &v[..12])
-                        } else {
-                            "***".to_string()
-                        }
-                    } else {
-                        v.to_string()
-                    };
-                    masked.insert(key, val);
-                }
-                debug_headers = Some(masked);
-            }
-            for (k, v) in h.iter() {
-                req = req.header(k, v);
-            }
+/// Connection and transport settings for the long-lived HTTP client owned by `ClobClient`.
+///
+/// Building one `reqwest::Client` up front lets every request reuse pooled, keep-alive
+/// connections instead of paying a fresh TLS handshake per call.
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    /// Overall per-request timeout (connect + send + read body).
+    pub timeout: Option<Duration>,
+    /// Timeout for establishing the TCP/TLS connection.
+    pub connect_timeout: Option<Duration>,
+    /// How long idle pooled connections are kept before being closed.
+    pub pool_idle_timeout: Option<Duration>,
+    /// Maximum idle connections kept per host.
+    pub pool_max_idle_per_host: Option<usize>,
+    /// TCP keep-alive interval for pooled sockets.
+    pub tcp_keepalive: Option<Duration>,
+    /// Optional proxy URL applied to all requests (http, https and socks5 schemes are accepted).
+    pub proxy: Option<String>,
+    /// Optional User-Agent header value.
+    pub user_agent: Option<String>,
+    /// Headers sent with every request (per-request headers take precedence).
+    pub default_headers: HashMap<String, String>,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(30)),
+            connect_timeout: Some(Duration::from_secs(10)),
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            pool_max_idle_per_host: None,
+            tcp_keepalive: Some(Duration::from_secs(60)),
+            proxy: None,
+            user_agent: None,
+            default_headers: HashMap::new(),
         }
-        if let Some(body) = opts.data {
-            // Serialize once for debug printing; req.json will produce the same representation
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                if let Ok(b) = serde_json::to_string(&body) {
-                    debug_body = Some(b);
-                }
-            }
-            req = req.json(&body);
+    }
+}
+
+impl HttpClientConfig {
+    /// Build a `reqwest::Client` from this configuration; invalid settings are `ClobError::Config`.
+    pub fn build(&self) -> Result<Client, ClobError> {
+        let mut builder = Client::builder();
+        if let Some(t) = self.timeout {
+            builder = builder.timeout(t);
         }
-        if let Some(params) = opts.params {
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                debug_params = Some(params.clone());
-            }
-            req = req.query(&params);
+        if let Some(t) = self.connect_timeout {
+            builder = builder.connect_timeout(t);
         }
-    }
-    if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-        eprintln!("[HTTP DEBUG] POST {}", endpoint);
-        if let Some(h) = &debug_headers {
-            eprintln!("  headers={:?}", h);
+        builder = builder.pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(n) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(n);
         }
-        if let Some(p) = &debug_params {
-            eprintln!("  params={:?}", p);
+        builder = builder.tcp_keepalive(self.tcp_keepalive);
+        if let Some(p) = &self.proxy {
+            let proxy = reqwest::Proxy::all(p)
+                .map_err(|e| ClobError::Config(format!("invalid proxy url: {}", e)))?;
+            builder = builder.proxy(proxy);
         }
-        if let Some(b) = &debug_body {
-            let preview = if b.len() > 800 {
-                format!("{}... ({} bytes)", &b[..800], b.len())
-            } else {
-                b.clone()
-            };
-            eprintln!("  body={}", preview);
+        if let Some(ua) = &self.user_agent {
+            builder = builder.user_agent(ua.clone());
         }
+        if !self.default_headers.is_empty() {
+            let mut map = HeaderMap::new();
+            for (k, v) in self.default_headers.iter() {
+                let name = HeaderName::from_bytes(k.as_bytes())
+                    .map_err(|e| ClobError::Config(format!("invalid header name {}: {}", k, e)))?;
+                let value = HeaderValue::from_str(v).map_err(|e| {
+                    ClobError::Config(format!("invalid header value for {}: {}", k, e))
+                })?;
+                map.insert(name, value);
+            }
+            builder = builder.default_headers(map);
+        }
+        builder
+            .build()
+            .map_err(|e| ClobError::Config(format!("failed to build http client: {}", e)))
     }
-    let resp = req
-        .send()
-        .await
-        .map_err(|e| ClobError::Other(format!("HTTP request failed: {}", e)))?;
-
-    let status = resp.status();
+}
 
-    // Check status code first, before trying to parse
-    if !status.is_success() {
-        // Get response body as text for error details
-        let body_text = resp
-            .text()
-            .await
-            .unwrap_or_else(|_| "<unable to read response body>".to_string());
+/// Process-wide client used by the free helper functions below, so even callers that do not
+/// go through `ClobClient` share one connection pool.
+pub fn default_http_client() -> &'static Client {
+    default_transport().client()
+}
 
-        eprintln!("❌ HTTP Error Response:");
-        eprintln!("   Status: {}", status);
-        eprintln!("   Endpoint: {}", endpoint);
-        eprintln!("   Response Body: {}", body_text);
+/// Transport wrapping `default_http_client()`.
+pub fn default_transport() -> &'static ReqwestTransport {
+    static TRANSPORT: OnceLock<ReqwestTransport> = OnceLock::new();
+    TRANSPORT.get_or_init(|| {
+        ReqwestTransport::new(
+            HttpClientConfig::default()
+                .build()
+                .expect("default http client config is valid"),
+        )
+    })
+}
 
-        return Err(ClobError::Other(format!(
-            "HTTP {} error from {}: {}",
-            status, endpoint, body_text
-        )));
+/// Mask credentials in a header map before it is logged.
+pub(crate) fn mask_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut masked = HashMap::new();
+    for (k, v) in headers.iter() {
+        let key = k.to_ascii_uppercase();
+        let val = if key.contains("PASSPHRASE") || key.contains("API_KEY") || key.contains("SECRET")
+        {
+            mask(v, 6, "***")
+        } else if key.contains("SIGNATURE") {
+            mask(v, 12, "...")
+        } else {
+            v.to_string()
+        };
+        masked.insert(k.to_string(), val);
     }
+    masked
+}
 
-    // Try to parse JSON, with detailed error message if it fails
-    let body_text = resp
-        .text()
-        .await
-        .map_err(|e| ClobError::Other(format!("Failed to read response body: {}", e)))?;
-
-    match serde_json::from_str::<R>(&body_text) {
-        Ok(val) => Ok(val),
-        Err(e) => {
-            eprintln!("❌ JSON Parse Error:");
-            eprintln!("   Endpoint: {}", endpoint);
-            eprintln!("   Error: {}", e);
-            eprintln!("   Response Body: {}", body_text);
-
-            Err(ClobError::Other(format!(
-                "Failed to parse JSON response from {}: {}. Response body: {}",
-                endpoint,
-                e,
-                if body_text.len() > 500 {
-                    format!(
-                        "{}... (truncated, {} bytes total)",
-                        &body_text[..500],
-                        body_text.len()
-                    )
-                } else {
-                    body_text
-                }
-            )))
-        }
+// Keep the first `keep` characters of a secret, or hide it entirely when it is that short.
+fn mask(v: &str, keep: usize, suffix: &str) -> String {
+    if v.chars().count() > keep {
+        format!("{}{}", v.chars().take(keep).collect::<String>(), suffix)
+    } else {
+        "***".to_string()
     }
 }
 
-pub async fn post(endpoint: &str, options: Option<RequestOptions>) -> Result<Value, ClobError> {
-    post_typed::<Value, Value>(endpoint, options).await
+// Truncate a body for logging.
+fn preview(body: &str, max: usize) -> String {
+    if body.len() > max {
+        let mut end = max;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}... ({} bytes)", &body[..end], body.len())
+    } else {
+        body.to_string()
+    }
 }
 
-pub async fn get_typed<R, B>(
+/// Build an `HttpRequest`, send it through `transport` and decode the JSON response into `R`.
+///
+/// All of `get_typed`, `post_typed` and `del_typed` funnel through here; `ClobClient` calls it
+/// with its own transport, retry policy and rate limiter. Every attempt, including retries, takes
+/// a token from the limiter first.
+pub async fn send_typed<R, B>(
+    transport: &dyn Transport,
+    retry: &RetryPolicy,
+    limiter: Option<&RateLimiter>,
+    method: &str,
     endpoint: &str,
     options: Option<RequestOptions<B>>,
 ) -> Result<R, ClobError>
@@ -164,113 +180,158 @@ where
     R: DeserializeOwned,
     B: Serialize,
 {
-    let client = Client::new();
-    let mut req = client.get(endpoint);
-    let mut debug_headers: Option<std::collections::HashMap<String, String>> = None;
-    let mut debug_params: Option<QueryParams> = None;
+    let mut req = HttpRequest {
+        method: method.to_string(),
+        url: endpoint.to_string(),
+        ..Default::default()
+    };
     if let Some(opts) = options {
         if let Some(h) = opts.headers {
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                let mut masked = std::collections::HashMap::new();
-                for (k, v) in h.iter() {
-                    let key = k.to_string();
-                    let val = if key.contains("PASSPHRASE") || key.contains("API_KEY") {
-                        if v.len() > 6 {
-                            format!("{}***", &v[..6])
-                        } else {
-                            "***".to_string()
-                        }
-                    } else if key.contains("SIGNATURE") {
-                        if v.len() > 12 {
-                            format!("{}...", &v[..12])
-                        } else {
-                            "***".to_string()
-                        }
-                    } else {
-                        v.to_string()
-                    };
-                    masked.insert(key, val);
-                }
-                debug_headers = Some(masked);
-            }
-            for (k, v) in h.iter() {
-                req = req.header(k, v);
-            }
+            req.headers = h;
+        }
+        // GET requests never carry a body
+        if let Some(body) = opts.data.filter(|_| method != GET) {
+            let b = serde_json::to_string(&body).map_err(|e| ClobError::Other(e.to_string()))?;
+            req.headers
+                .insert("Content-Type".to_string(), "application/json".to_string());
+            req.body = Some(b);
         }
         if let Some(params) = opts.params {
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                debug_params = Some(params.clone());
-            }
-            req = req.query(&params);
+            req.query = params.into_iter().collect();
         }
     }
-    if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-        eprintln!("[HTTP DEBUG] GET {}", endpoint);
-        if let Some(h) = &debug_headers {
-            eprintln!("  headers={:?}", h);
-        }
-        if let Some(p) = &debug_params {
-            eprintln!("  params={:?}", p);
-        }
+    let span = tracing::debug_span!(
+        "clob_request",
+        method,
+        endpoint,
+        status = tracing::field::Empty,
+        attempts = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    );
+    async move {
+        tracing::trace!(
+            headers = ?mask_headers(&req.headers),
+            query = ?req.query,
+            body = req.body.as_deref().map(|b| preview(b, 800)).unwrap_or_default(),
+            "sending request"
+        );
+        let started = Instant::now();
+        let group = EndpointGroup::classify(method, endpoint);
+        let mut attempt = 1;
+        let outcome = loop {
+            if let Some(l) = limiter {
+                l.acquire(group).await;
+            }
+            let outcome = transport.send(req.clone()).await;
+            match retry.next_delay(method, attempt, &outcome) {
+                Some(delay) => {
+                    tracing::debug!(
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        status = outcome.as_ref().ok().map(|r| r.status),
+                        error = outcome.as_ref().err().map(|e| e.to_string()),
+                        "retrying request"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => break outcome,
+            }
+        };
+        let span = tracing::Span::current();
+        span.record("attempts", attempt);
+        span.record("latency_ms", started.elapsed().as_millis() as u64);
+        let resp = match outcome {
+            Ok(resp) => resp,
+            Err(e) => {
+                tracing::warn!(error = %e, "request failed");
+                return Err(e);
+            }
+        };
+        span.record("status", resp.status);
+        tracing::debug!("response received");
+        decode_response(endpoint, resp)
     }
-    let resp = req
-        .send()
-        .await
-        .map_err(|e| ClobError::Other(format!("HTTP request failed: {}", e)))?;
-
-    let status = resp.status();
+    .instrument(span)
+    .await
+}
 
+fn decode_response<R: DeserializeOwned>(
+    endpoint: &str,
+    resp: HttpResponse,
+) -> Result<R, ClobError> {
     // Check status code first, before trying to parse
-    if !status.is_success() {
-        // Get response body as text for error details
-        let body_text = resp
-            .text()
-            .await
-            .unwrap_or_else(|_| "<unable to read response body>".to_string());
+    if !resp.is_success() {
+        let body_text = resp.body;
 
-        eprintln!("❌ HTTP Error Response:");
-        eprintln!("   Status: {}", status);
-        eprintln!("   Endpoint: {}", endpoint);
-        eprintln!("   Response Body: {}", body_text);
+        tracing::warn!(
+            status = resp.status,
+            body = %preview(&body_text, 2000),
+            "non-success response"
+        );
 
-        return Err(ClobError::Other(format!(
-            "HTTP {} error from {}: {}",
-            status, endpoint, body_text
-        )));
+        return Err(ClobError::http(resp.status, endpoint, body_text));
     }
 
     // Try to parse JSON, with detailed error message if it fails
-    let body_text = resp
-        .text()
-        .await
-        .map_err(|e| ClobError::Other(format!("Failed to read response body: {}", e)))?;
+    let body_text = resp.body;
 
     match serde_json::from_str::<R>(&body_text) {
         Ok(val) => Ok(val),
         Err(e) => {
-            eprintln!("❌ JSON Parse Error:");
-            eprintln!("   Endpoint: {}", endpoint);
-            eprintln!("   Error: {}", e);
-            eprintln!("   Response Body: {}", body_text);
+            tracing::warn!(
+                error = %e,
+                body = %preview(&body_text, 2000),
+                "failed to decode response"
+            );
 
-            Err(ClobError::Other(format!(
-                "Failed to parse JSON response from {}: {}. Response body: {}",
-                endpoint,
-                e,
-                if body_text.len() > 500 {
-                    format!(
-                        "{}... (truncated, {} bytes total)",
-                        &body_text[..500],
-                        body_text.len()
-                    )
-                } else {
-                    body_text
-                }
-            )))
+            Err(ClobError::decode(endpoint, e, body_text))
         }
     }
 }
 
+pub async fn post_typed<R, B>(
+    endpoint: &str,
+    options: Option<RequestOptions<B>>,
+) -> Result<R, ClobError>
+where
+    R: DeserializeOwned,
+    B: Serialize,
+{
+    send_typed(
+        default_transport(),
+        &RetryPolicy::default(),
+        None,
+        POST,
+        endpoint,
+        options,
+    )
+    .await
+}
+
+pub async fn post(endpoint: &str, options: Option<RequestOptions>) -> Result<Value, ClobError> {
+    post_typed::<Value, Value>(endpoint, options).await
+}
+
+pub async fn get_typed<R, B>(
+    endpoint: &str,
+    options: Option<RequestOptions<B>>,
+) -> Result<R, ClobError>
+where
+    R: DeserializeOwned,
+    B: Serialize,
+{
+    send_typed(
+        default_transport(),
+        &RetryPolicy::default(),
+        None,
+        GET,
+        endpoint,
+        options,
+    )
+    .await
+}
+
 pub async fn get(endpoint: &str, options: Option<RequestOptions>) -> Result<Value, ClobError> {
     get_typed::<Value, Value>(endpoint, options).await
 }
@@ -283,128 +344,15 @@ where
     R: DeserializeOwned,
     B: Serialize,
 {
-    let client = Client::new();
-    let mut req = client.delete(endpoint);
-    let mut debug_headers: Option<std::collections::HashMap<String, String>> = None;
-    let mut debug_body: Option<String> = None;
-    let mut debug_params: Option<QueryParams> = None;
-    if let Some(opts) = options {
-        if let Some(h) = opts.headers {
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                let mut masked = std::collections::HashMap::new();
-                for (k, v) in h.iter() {
-                    let key = k.to_string();
-                    let val = if key.contains("PASSPHRASE") || key.contains("API_KEY") {
-                        if v.len() > 6 {
-                            format!("{}***", &v[..6])
-                        } else {
-                            "***".to_string()
-                        }
-                    } else if key.contains("SIGNATURE") {
-                        if v.len() > 12 {
-                            format!("{}...", &v[..12])
-                        } else {
-                            "***".to_string()
-                        }
-                    } else {
-                        v.to_string()
-                    };
-                    masked.insert(key, val);
-                }
-                debug_headers = Some(masked);
-            }
-            for (k, v) in h.iter() {
-                req = req.header(k, v);
-            }
-        }
-        if let Some(body) = opts.data {
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                if let Ok(b) = serde_json::to_string(&body) {
-                    debug_body = Some(b);
-                }
-            }
-            req = req.json(&body);
-        }
-        if let Some(params) = opts.params {
-            if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-                debug_params = Some(params.clone());
-            }
-            req = req.query(&params);
-        }
-    }
-    if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-        eprintln!("[HTTP DEBUG] DELETE {}", endpoint);
-        if let Some(h) = &debug_headers {
-            eprintln!("  headers={:?}", h);
-        }
-        if let Some(p) = &debug_params {
-            eprintln!("  params={:?}", p);
-        }
-        if let Some(b) = &debug_body {
-            let preview = if b.len() > 800 {
-                format!("{}... ({} bytes)", &b[..800], b.len())
-            } else {
-                b.clone()
-            };
-            eprintln!("  body={}", preview);
-        }
-    }
-    let resp = req
-        .send()
-        .await
-        .map_err(|e| ClobError::Other(format!("HTTP request failed: {}", e)))?;
-
-    let status = resp.status();
-
-    // Check status code first, before trying to parse
-    if !status.is_success() {
-        // Get response body as text for error details
-        let body_text = resp
-            .text()
-            .await
-            .unwrap_or_else(|_| "<unable to read response body>".to_string());
-
-        eprintln!("❌ HTTP Error Response:");
-        eprintln!("   Status: {}", status);
-        eprintln!("   Endpoint: {}", endpoint);
-        eprintln!("   Response Body: {}", body_text);
-
-        return Err(ClobError::Other(format!(
-            "HTTP {} error from {}: {}",
-            status, endpoint, body_text
-        )));
-    }
-
-    // Try to parse JSON, with detailed error message if it fails
-    let body_text = resp
-        .text()
-        .await
-        .map_err(|e| ClobError::Other(format!("Failed to read response body: {}", e)))?;
-
-    match serde_json::from_str::<R>(&body_text) {
-        Ok(val) => Ok(val),
-        Err(e) => {
-            eprintln!("❌ JSON Parse Error:");
-            eprintln!("   Endpoint: {}", endpoint);
-            eprintln!("   Error: {}", e);
-            eprintln!("   Response Body: {}", body_text);
-
-            Err(ClobError::Other(format!(
-                "Failed to parse JSON response from {}: {}. Response body: {}",
-                endpoint,
-                e,
-                if body_text.len() > 500 {
-                    format!(
-                        "{}... (truncated, {} bytes total)",
-                        &body_text[..500],
-                        body_text.len()
-                    )
-                } else {
-                    body_text
-                }
-            )))
-        }
-    }
+    send_typed(
+        default_transport(),
+        &RetryPolicy::default(),
+        None,
+        DELETE,
+        endpoint,
+        options,
+    )
+    .await
 }
 
 pub async fn del(endpoint: &str, options: Option<RequestOptions>) -> Result<Value, ClobError> {
@@ -426,3 +374,49 @@ pub fn parse_drop_notification_params(ids: Option<&Vec<String>>) -> QueryParams
     }
     params
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_client_config_rejects_invalid_settings() {
+        assert!(HttpClientConfig::default().build().is_ok());
+
+        let bad_proxy = HttpClientConfig {
+            proxy: Some("not a url".into()),
+            ..Default::default()
+        };
+        assert!(matches!(bad_proxy.build(), Err(ClobError::Config(m)) if m.contains("proxy")));
+
+        for (name, value) in [("bad header", "v"), ("x-ok", "line\nbreak")] {
+            let cfg = HttpClientConfig {
+                default_headers: HashMap::from([(name.to_string(), value.to_string())]),
+                ..Default::default()
+            };
+            assert!(
+                matches!(cfg.build(), Err(ClobError::Config(m)) if m.contains("invalid header"))
+            );
+        }
+    }
+
+    #[test]
+    fn masks_credentials_in_headers() {
+        let headers = HashMap::from([
+            ("POLY_API_KEY".to_string(), "0123456789abcdef".to_string()),
+            ("POLY_PASSPHRASE".to_string(), "pässphräse-long".to_string()),
+            ("POLY_BUILDER_SECRET".to_string(), "short".to_string()),
+            (
+                "POLY_SIGNATURE".to_string(),
+                "0xabcdef0123456789abcdef".to_string(),
+            ),
+            ("POLY_TIMESTAMP".to_string(), "1700000000".to_string()),
+        ]);
+        let masked = mask_headers(&headers);
+        assert_eq!(masked["POLY_API_KEY"], "012345***");
+        assert_eq!(masked["POLY_PASSPHRASE"], "pässph***");
+        assert_eq!(masked["POLY_BUILDER_SECRET"], "***");
+        assert_eq!(masked["POLY_SIGNATURE"], "0xabcdef0123...");
+        assert_eq!(masked["POLY_TIMESTAMP"], "1700000000");
+    }
+}
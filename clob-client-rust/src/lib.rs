@@ -1,28 +1,45 @@
+pub mod cache;
 pub mod client;
+pub mod clock;
+pub mod config;
 pub mod constants;
+pub mod credentials;
 pub mod endpoints;
 pub mod errors;
 pub mod exchange_consts;
 pub mod exchange_order_builder;
 pub mod headers;
 pub mod http_helpers;
+pub mod order_book;
 pub mod order_builder;
+pub mod rate_limit;
+pub mod retry;
 pub mod signer_adapter;
 pub mod signing;
+pub mod transport;
 pub mod types;
 pub mod utilities;
+pub mod ws;
 
+pub use cache::*;
 pub use client::*;
+pub use clock::*;
+pub use config::*;
 pub use constants::*;
+pub use credentials::*;
 pub use endpoints::*;
 pub use errors::*;
 pub use exchange_consts::*;
 pub use exchange_order_builder::*;
 pub use headers::*;
 pub use http_helpers::*;
+pub use order_book::*;
 pub use order_builder::*;
+pub use rate_limit::*;
+pub use retry::*;
 pub use signer_adapter::*;
 pub use signing::*;
+pub use transport::*;
 pub use types::*;
 pub use utilities::*;
 
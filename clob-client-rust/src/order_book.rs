@@ -0,0 +1,365 @@
+use crate::errors::ClobError;
+use crate::types::{OrderBookSummary, OrderSummary, Side};
+use crate::utilities::generate_orderbook_summary_hash;
+use crate::ws::{BookEvent, MarketEvent, PriceChange};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// One aggregated price level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceLevel {
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+/// Result of applying an update to a `LocalOrderBook`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookUpdate {
+    /// The update was applied (and matched the server hash when one was provided).
+    Applied,
+    /// The update was for another asset and was ignored.
+    Ignored,
+    /// Local state diverged from the server (hash mismatch, missed updates after a reconnect).
+    /// Reseed from `get_order_book` before trusting the book again.
+    ResyncNeeded { expected: String, computed: String },
+}
+
+// Server strings are kept next to the parsed values so the hash is computed over exactly
+// what the server sent.
+#[derive(Debug, Clone)]
+struct Level {
+    size: Decimal,
+    raw_price: String,
+    raw_size: String,
+}
+
+/// Order book for a single asset, seeded from a `/book` snapshot and kept current from
+/// market channel deltas.
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    pub market: String,
+    pub asset_id: String,
+    pub timestamp: String,
+    pub min_order_size: String,
+    pub tick_size: String,
+    pub neg_risk: bool,
+    /// Last hash received from the server.
+    pub hash: String,
+    bids: BTreeMap<Decimal, Level>,
+    asks: BTreeMap<Decimal, Level>,
+    needs_resync: bool,
+}
+
+fn parse_decimal(field: &str, v: &str) -> Result<Decimal, ClobError> {
+    Decimal::from_str(v.trim())
+        .map_err(|e| ClobError::Validation(format!("invalid {} {:?}: {}", field, v, e)))
+}
+
+fn build_side(levels: &[OrderSummary]) -> Result<BTreeMap<Decimal, Level>, ClobError> {
+    let mut out = BTreeMap::new();
+    for l in levels {
+        let price = parse_decimal("price", &l.price)?;
+        let size = parse_decimal("size", &l.size)?;
+        if size.is_zero() {
+            continue;
+        }
+        out.insert(
+            price,
+            Level {
+                size,
+                raw_price: l.price.clone(),
+                raw_size: l.size.clone(),
+            },
+        );
+    }
+    Ok(out)
+}
+
+impl LocalOrderBook {
+    /// Seed from a `get_order_book` snapshot.
+    pub fn from_summary(summary: &OrderBookSummary) -> Result<Self, ClobError> {
+        Ok(Self {
+            market: summary.market.clone(),
+            asset_id: summary.asset_id.clone(),
+            timestamp: summary.timestamp.clone(),
+            min_order_size: summary.min_order_size.clone(),
+            tick_size: summary.tick_size.clone(),
+            neg_risk: summary.neg_risk,
+            hash: summary.hash.clone(),
+            bids: build_side(&summary.bids)?,
+            asks: build_side(&summary.asks)?,
+            needs_resync: false,
+        })
+    }
+
+    /// Replace the book with a fresh snapshot and clear the resync flag.
+    pub fn reseed(&mut self, summary: &OrderBookSummary) -> Result<(), ClobError> {
+        *self = Self::from_summary(summary)?;
+        Ok(())
+    }
+
+    /// Replace levels from a market channel `book` snapshot.
+    pub fn apply_book(&mut self, book: &BookEvent) -> Result<BookUpdate, ClobError> {
+        if book.asset_id != self.asset_id {
+            return Ok(BookUpdate::Ignored);
+        }
+        self.bids = build_side(&book.bids)?;
+        self.asks = build_side(&book.asks)?;
+        self.timestamp = book.timestamp.clone();
+        self.hash = book.hash.clone();
+        self.needs_resync = false;
+        Ok(BookUpdate::Applied)
+    }
+
+    /// Apply one level update from a `price_change` event. When the change carries a hash, the
+    /// resulting book is checked against it.
+    pub fn apply_price_change(
+        &mut self,
+        change: &PriceChange,
+        timestamp: &str,
+    ) -> Result<BookUpdate, ClobError> {
+        if change.asset_id != self.asset_id {
+            return Ok(BookUpdate::Ignored);
+        }
+        let price = parse_decimal("price", &change.price)?;
+        let size = parse_decimal("size", &change.size)?;
+        let side = match change.side {
+            Side::BUY => &mut self.bids,
+            Side::SELL => &mut self.asks,
+        };
+        if size.is_zero() {
+            side.remove(&price);
+        } else {
+            side.insert(
+                price,
+                Level {
+                    size,
+                    raw_price: change.price.clone(),
+                    raw_size: change.size.clone(),
+                },
+            );
+        }
+        if !timestamp.is_empty() {
+            self.timestamp = timestamp.to_string();
+        }
+        match change.hash.as_deref().filter(|h| !h.is_empty()) {
+            Some(expected) => Ok(self.check_hash(expected)),
+            None => Ok(BookUpdate::Applied),
+        }
+    }
+
+    /// Route a market channel event to this book. `Reconnected` flags the book for resync since
+    /// deltas may have been lost.
+    pub fn apply_market_event(&mut self, event: &MarketEvent) -> Result<BookUpdate, ClobError> {
+        match event {
+            MarketEvent::Book(book) => self.apply_book(book),
+            MarketEvent::PriceChange(pc) => {
+                let mut result = BookUpdate::Ignored;
+                for change in pc.price_changes.iter() {
+                    match self.apply_price_change(change, &pc.timestamp)? {
+                        BookUpdate::Ignored => {}
+                        r @ BookUpdate::ResyncNeeded { .. } => return Ok(r),
+                        BookUpdate::Applied => result = BookUpdate::Applied,
+                    }
+                }
+                Ok(result)
+            }
+            MarketEvent::TickSizeChange(t) if t.asset_id == self.asset_id => {
+                self.tick_size = t.new_tick_size.clone();
+                Ok(BookUpdate::Applied)
+            }
+            MarketEvent::Reconnected => {
+                self.needs_resync = true;
+                Ok(BookUpdate::ResyncNeeded {
+                    expected: self.hash.clone(),
+                    computed: String::new(),
+                })
+            }
+            _ => Ok(BookUpdate::Ignored),
+        }
+    }
+
+    fn check_hash(&mut self, expected: &str) -> BookUpdate {
+        self.hash = expected.to_string();
+        let computed = self.compute_hash();
+        if computed == expected {
+            BookUpdate::Applied
+        } else {
+            self.needs_resync = true;
+            BookUpdate::ResyncNeeded {
+                expected: expected.to_string(),
+                computed,
+            }
+        }
+    }
+
+    /// Whether the book must be reseeded before it can be trusted.
+    pub fn needs_resync(&self) -> bool {
+        self.needs_resync
+    }
+
+    /// Snapshot in server layout (bids ascending, asks descending, best level last) with the
+    /// last server hash.
+    pub fn to_summary(&self) -> OrderBookSummary {
+        let level = |(_, l): (&Decimal, &Level)| OrderSummary {
+            price: l.raw_price.clone(),
+            size: l.raw_size.clone(),
+        };
+        OrderBookSummary {
+            market: self.market.clone(),
+            asset_id: self.asset_id.clone(),
+            timestamp: self.timestamp.clone(),
+            bids: self.bids.iter().map(level).collect(),
+            asks: self.asks.iter().rev().map(level).collect(),
+            min_order_size: self.min_order_size.clone(),
+            tick_size: self.tick_size.clone(),
+            neg_risk: self.neg_risk,
+            hash: self.hash.clone(),
+        }
+    }
+
+    /// Hash of the current book, computed the way the server does (over the summary with an
+    /// empty `hash` field).
+    pub fn compute_hash(&self) -> String {
+        let mut summary = self.to_summary();
+        summary.hash = String::new();
+        generate_orderbook_summary_hash(&summary)
+    }
+
+    /// Compare the local book against the last server hash.
+    pub fn verify_hash(&self) -> bool {
+        !self.hash.is_empty() && self.compute_hash() == self.hash
+    }
+
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.iter().next_back().map(to_level)
+    }
+
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.iter().next().map(to_level)
+    }
+
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    pub fn midpoint(&self) -> Option<Decimal> {
+        Some((self.best_ask()?.price + self.best_bid()?.price) / Decimal::TWO)
+    }
+
+    /// All levels of one side, best price first.
+    pub fn levels(&self, side: Side) -> Vec<PriceLevel> {
+        match side {
+            Side::BUY => self.bids.iter().rev().map(to_level).collect(),
+            Side::SELL => self.asks.iter().map(to_level).collect(),
+        }
+    }
+
+    /// Top `n` levels of one side, best price first.
+    pub fn depth(&self, side: Side, n: usize) -> Vec<PriceLevel> {
+        let mut levels = self.levels(side);
+        levels.truncate(n);
+        levels
+    }
+
+    /// Size resting at exactly `price`.
+    pub fn size_at(&self, side: Side, price: Decimal) -> Decimal {
+        let book = match side {
+            Side::BUY => &self.bids,
+            Side::SELL => &self.asks,
+        };
+        book.get(&price).map(|l| l.size).unwrap_or_default()
+    }
+
+    /// Total size on one side priced at `limit` or better (bids >= limit, asks <= limit).
+    pub fn size_within(&self, side: Side, limit: Decimal) -> Decimal {
+        match side {
+            Side::BUY => self.bids.range(limit..).map(|(_, l)| l.size).sum(),
+            Side::SELL => self.asks.range(..=limit).map(|(_, l)| l.size).sum(),
+        }
+    }
+}
+
+fn to_level((price, l): (&Decimal, &Level)) -> PriceLevel {
+    PriceLevel {
+        price: *price,
+        size: l.size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lvl(price: &str, size: &str) -> OrderSummary {
+        OrderSummary {
+            price: price.to_string(),
+            size: size.to_string(),
+        }
+    }
+
+    #[test]
+    fn applies_deltas_and_detects_hash_mismatch() {
+        let summary = OrderBookSummary {
+            market: "0xm".into(),
+            asset_id: "1".into(),
+            timestamp: "100".into(),
+            bids: vec![lvl("0.47", "20"), lvl("0.48", "10")],
+            asks: vec![lvl("0.53", "7"), lvl("0.52", "5")],
+            min_order_size: "5".into(),
+            tick_size: "0.01".into(),
+            neg_risk: false,
+            hash: String::new(),
+        };
+        let mut book = LocalOrderBook::from_summary(&summary).unwrap();
+        assert_eq!(book.best_bid().unwrap().price, Decimal::new(48, 2));
+        assert_eq!(book.best_ask().unwrap().price, Decimal::new(52, 2));
+        assert_eq!(book.spread(), Some(Decimal::new(4, 2)));
+        assert_eq!(
+            book.size_within(Side::SELL, Decimal::new(53, 2)),
+            Decimal::from(12)
+        );
+
+        // bid at 0.48 removed, new bid at 0.49; hash computed over the expected server book
+        let mut expected = summary.clone();
+        expected.timestamp = "101".into();
+        expected.bids = vec![lvl("0.47", "20"), lvl("0.49", "3")];
+        let hash = generate_orderbook_summary_hash(&expected);
+        let change = |price: &str, size: &str, hash: Option<String>| PriceChange {
+            asset_id: "1".into(),
+            price: price.into(),
+            size: size.into(),
+            side: Side::BUY,
+            hash,
+            best_bid: None,
+            best_ask: None,
+        };
+        assert_eq!(
+            book.apply_price_change(&change("0.48", "0", None), "101")
+                .unwrap(),
+            BookUpdate::Applied
+        );
+        assert_eq!(
+            book.apply_price_change(&change("0.49", "3", Some(hash)), "101")
+                .unwrap(),
+            BookUpdate::Applied
+        );
+        assert!(book.verify_hash());
+        assert_eq!(
+            book.depth(Side::BUY, 1),
+            vec![PriceLevel {
+                price: Decimal::new(49, 2),
+                size: Decimal::from(3)
+            }]
+        );
+
+        let r = book
+            .apply_price_change(&change("0.45", "1", Some("bogus".into())), "102")
+            .unwrap();
+        assert!(matches!(r, BookUpdate::ResyncNeeded { .. }));
+        assert!(book.needs_resync());
+        book.reseed(&summary).unwrap();
+        assert!(!book.needs_resync());
+    }
+}
@@ -1,7 +1,9 @@
-use crate::errors::ClobError;
+use crate::errors::{ClobError, OrderValidationError, OrderViolation};
 use crate::exchange_order_builder::ExchangeOrderBuilder;
 use crate::signing::Eip712Signer;
-use crate::types::{OrderData, Side, SignatureType, SignedOrder};
+use crate::types::{
+    OrderData, OrderType, Side, SignatureType, SignedOrder, UserMarketOrder, UserOrder,
+};
 use crate::utilities::{decimal_places, round_down, round_normal, round_up};
 use rust_decimal::prelude::Decimal;
 use rust_decimal::prelude::ToPrimitive;
@@ -54,14 +56,14 @@ pub fn rounding_config() -> HashMap<&'static str, RoundConfig> {
 
 pub struct RawAmounts {
     pub side: Side,
-    pub raw_maker_amt: f64,
-    pub raw_taker_amt: f64,
+    pub raw_maker_amt: Decimal,
+    pub raw_taker_amt: Decimal,
 }
 
 pub fn get_order_raw_amounts(
     side: Side,
-    size: f64,
-    price: f64,
+    size: Decimal,
+    price: Decimal,
     round_config: &RoundConfig,
 ) -> RawAmounts {
     let raw_price = round_normal(price, round_config.price);
@@ -97,21 +99,160 @@ pub fn get_order_raw_amounts(
     }
 }
 
-fn parse_units(value: &str, decimals: u32) -> Result<String, ClobError> {
-    // Use Decimal for exact decimal arithmetic
-    let d = value
-        .parse::<Decimal>()
-        .map_err(|e| ClobError::Other(format!("parse decimal error: {}", e)))?;
+fn parse_units(value: Decimal, decimals: u32) -> Result<String, ClobError> {
     let factor = Decimal::new(10i64.pow(decimals), 0);
-    let scaled = d * factor;
+    let scaled = value
+        .checked_mul(factor)
+        .ok_or_else(|| ClobError::Validation("scaled value out of range".to_string()))?;
     // Truncate toward zero
-    let scaled_i = scaled.trunc();
-    match scaled_i.to_i128() {
+    match scaled.trunc().to_i128() {
         Some(i) => Ok(i.to_string()),
-        None => Err(ClobError::Other("scaled value out of range".to_string())),
+        None => Err(ClobError::Validation(
+            "scaled value out of range".to_string(),
+        )),
     }
 }
 
+/// Pre-flight checks for a limit order, collecting every violation. `order_type` is the type
+/// the order will be posted with (type specific checks are skipped when `None`);
+/// `min_order_size` comes from the market's order book.
+pub fn validate_order(
+    user_order: &UserOrder,
+    order_type: Option<&OrderType>,
+    tick_size: &str,
+    min_order_size: Option<Decimal>,
+) -> Result<(), OrderValidationError> {
+    let mut violations = Vec::new();
+    let round_config = check_price(user_order.price, tick_size, &mut violations);
+    if let Some(min) = min_order_size.filter(|min| user_order.size < *min) {
+        violations.push(OrderViolation::SizeBelowMinimum {
+            size: user_order.size,
+            min_order_size: min,
+        });
+    }
+    if let Some(rc) = &round_config {
+        let ra = get_order_raw_amounts(
+            user_order.side.clone(),
+            user_order.size,
+            user_order.price,
+            rc,
+        );
+        check_amounts(&ra, &mut violations);
+    }
+    match (user_order.expiration.filter(|e| *e > 0), order_type) {
+        (Some(expiration), _) => {
+            let now = unix_now();
+            if expiration <= now {
+                violations.push(OrderViolation::ExpirationInPast { expiration, now });
+            }
+        }
+        (None, Some(OrderType::GTD)) => violations.push(OrderViolation::MissingExpiration),
+        _ => {}
+    }
+    if let Some(t @ (OrderType::FOK | OrderType::FAK)) = order_type {
+        violations.push(OrderViolation::MarketOnlyOrderType {
+            order_type: t.clone(),
+        });
+    }
+    OrderValidationError::check(violations)
+}
+
+/// Pre-flight checks for a market order. For buys `amount` is collateral, so the minimum
+/// size is compared against `amount / price` shares.
+pub fn validate_market_order(
+    user_market_order: &UserMarketOrder,
+    tick_size: &str,
+    min_order_size: Option<Decimal>,
+) -> Result<(), OrderValidationError> {
+    let mut violations = Vec::new();
+    let price = user_market_order.price;
+    let round_config = check_price(price, tick_size, &mut violations);
+    if let Some(min) = min_order_size {
+        let size = match user_market_order.side {
+            Side::BUY => user_market_order
+                .amount
+                .checked_div(price)
+                .unwrap_or_default(),
+            Side::SELL => user_market_order.amount,
+        };
+        if size < min {
+            violations.push(OrderViolation::SizeBelowMinimum {
+                size,
+                min_order_size: min,
+            });
+        }
+    }
+    if let Some(rc) = &round_config {
+        let ra = get_market_order_raw_amounts(
+            user_market_order.side.clone(),
+            user_market_order.amount,
+            price,
+            rc,
+        );
+        check_amounts(&ra, &mut violations);
+    }
+    if !matches!(
+        user_market_order.order_type,
+        OrderType::FOK | OrderType::FAK
+    ) {
+        violations.push(OrderViolation::LimitOnlyOrderType {
+            order_type: user_market_order.order_type.clone(),
+        });
+    }
+    OrderValidationError::check(violations)
+}
+
+// Range and tick alignment; returns the rounding config when the tick size is supported.
+fn check_price(
+    price: Decimal,
+    tick_size: &str,
+    violations: &mut Vec<OrderViolation>,
+) -> Option<RoundConfig> {
+    let (Ok(tick), Some(rc)) = (
+        tick_size.parse::<Decimal>(),
+        rounding_config().remove(tick_size),
+    ) else {
+        violations.push(OrderViolation::UnsupportedTickSize(tick_size.to_string()));
+        return None;
+    };
+    if price < tick || price > Decimal::ONE - tick {
+        violations.push(OrderViolation::PriceOutOfRange {
+            price,
+            min: tick,
+            max: Decimal::ONE - tick,
+        });
+    }
+    if !(price % tick).is_zero() {
+        violations.push(OrderViolation::PriceNotOnTick {
+            price,
+            tick_size: tick,
+        });
+    }
+    Some(rc)
+}
+
+fn check_amounts(ra: &RawAmounts, violations: &mut Vec<OrderViolation>) {
+    let (Ok(maker_amount), Ok(taker_amount)) = (
+        parse_units(ra.raw_maker_amt, 6),
+        parse_units(ra.raw_taker_amt, 6),
+    ) else {
+        return;
+    };
+    if maker_amount == "0" || taker_amount == "0" {
+        violations.push(OrderViolation::ZeroAmount {
+            maker_amount,
+            taker_amount,
+        });
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 pub async fn build_order_creation_args(
     signer: &str,
     maker: &str,
@@ -126,8 +267,8 @@ pub async fn build_order_creation_args(
         round_config,
     );
 
-    let maker_amount = parse_units(&ra.raw_maker_amt.to_string(), 6)?; // COLLATERAL_TOKEN_DECIMALS = 6
-    let taker_amount = parse_units(&ra.raw_taker_amt.to_string(), 6)?;
+    let maker_amount = parse_units(ra.raw_maker_amt, 6)?; // COLLATERAL_TOKEN_DECIMALS = 6
+    let taker_amount = parse_units(ra.raw_taker_amt, 6)?;
 
     let taker = if let Some(t) = &user_order.taker {
         if !t.is_empty() {
@@ -139,8 +280,8 @@ pub async fn build_order_creation_args(
         "0x0000000000000000000000000000000000000000".to_string()
     };
 
-    // fee_rate_bps 必填，直接转为字符串
-    let fee_rate_bps = user_order.fee_rate_bps.to_string();
+    // fee_rate_bps 未指定时按 0 签名（ClobClient::create_order 会先填入市场费率）
+    let fee_rate_bps = user_order.fee_rate_bps.unwrap_or(0.0).to_string();
     let nonce = user_order
         .nonce
         .map(|v| v.to_string())
@@ -166,7 +307,7 @@ pub async fn build_order_creation_args(
 
 // Placeholder for build_order which uses exchange order builder and signing
 pub async fn build_order(
-    signer: &impl crate::signing::Eip712Signer,
+    signer: &(impl crate::signing::Eip712Signer + ?Sized),
     exchange_address: &str,
     chain_id: i32,
     order_data: OrderData,
@@ -177,15 +318,16 @@ pub async fn build_order(
 
 pub fn get_market_order_raw_amounts(
     side: Side,
-    amount: f64,
-    price: f64,
+    amount: Decimal,
+    price: Decimal,
     round_config: &RoundConfig,
 ) -> RawAmounts {
     let raw_price = round_down(price, round_config.price);
 
     if let Side::BUY = side {
         let raw_maker_amt = round_down(amount, round_config.size);
-        let mut raw_taker_amt = raw_maker_amt / raw_price;
+        // 价格为 0 时无法换算，taker 数量置 0（交易所会拒绝该订单）
+        let mut raw_taker_amt = raw_maker_amt.checked_div(raw_price).unwrap_or_default();
         if decimal_places(raw_taker_amt) > round_config.amount {
             raw_taker_amt = round_up(raw_taker_amt, round_config.amount + 4);
             if decimal_places(raw_taker_amt) > round_config.amount {
@@ -214,7 +356,7 @@ pub fn get_market_order_raw_amounts(
     }
 }
 
-pub struct OrderBuilder<'a, S: Eip712Signer> {
+pub struct OrderBuilder<'a, S: Eip712Signer + ?Sized> {
     signer: &'a S,
     chain_id: i64,
     signature_type: SignatureType,
@@ -241,7 +383,7 @@ impl Default for BuilderConfig {
     }
 }
 
-impl<'a, S: Eip712Signer> OrderBuilder<'a, S> {
+impl<'a, S: Eip712Signer + ?Sized> OrderBuilder<'a, S> {
     pub fn new(
         signer: &'a S,
         chain_id: i64,
@@ -260,7 +402,7 @@ impl<'a, S: Eip712Signer> OrderBuilder<'a, S> {
         Self {
             signer,
             chain_id,
-            signature_type: cfg.signature_type.clone(),
+            signature_type: cfg.signature_type,
             funder_address: cfg.funder_address.clone(),
         }
     }
@@ -270,18 +412,31 @@ impl<'a, S: Eip712Signer> OrderBuilder<'a, S> {
         exchange_address: &str,
         user_order: &crate::types::UserOrder,
         options_tick: &str,
+    ) -> Result<crate::types::SignedOrder, ClobError> {
+        validate_order(user_order, None, options_tick, None)?;
+        self.sign_order(exchange_address, user_order, options_tick)
+            .await
+    }
+
+    // `build_order` without validation, for callers that already ran `validate_order` with the
+    // market's order type and minimum size.
+    pub(crate) async fn sign_order(
+        &self,
+        exchange_address: &str,
+        user_order: &crate::types::UserOrder,
+        options_tick: &str,
     ) -> Result<crate::types::SignedOrder, ClobError> {
         let rc_map = rounding_config();
         let round_config = rc_map
             .get(options_tick)
-            .ok_or(ClobError::Other("invalid tick size".to_string()))?;
+            .ok_or(ClobError::Validation("invalid tick size".to_string()))?;
         let eoa_addr = self.signer.get_address().await?;
         let maker = self.funder_address.clone().unwrap_or(eoa_addr.clone());
 
         let order_data = build_order_creation_args(
             &eoa_addr,
             &maker,
-            self.signature_type.clone(),
+            self.signature_type,
             user_order,
             round_config,
         )
@@ -299,17 +454,18 @@ impl<'a, S: Eip712Signer> OrderBuilder<'a, S> {
         options_tick: &str,
         forced_salt: &str,
     ) -> Result<crate::types::SignedOrder, ClobError> {
+        validate_order(user_order, None, options_tick, None)?;
         let rc_map = rounding_config();
         let round_config = rc_map
             .get(options_tick)
-            .ok_or(ClobError::Other("invalid tick size".to_string()))?;
+            .ok_or(ClobError::Validation("invalid tick size".to_string()))?;
         let eoa_addr = self.signer.get_address().await?;
         let maker = self.funder_address.clone().unwrap_or(eoa_addr.clone());
 
         let order_data = build_order_creation_args(
             &eoa_addr,
             &maker,
-            self.signature_type.clone(),
+            self.signature_type,
             user_order,
             round_config,
         )
@@ -326,18 +482,30 @@ impl<'a, S: Eip712Signer> OrderBuilder<'a, S> {
         exchange_address: &str,
         user_market_order: &crate::types::UserMarketOrder,
         options_tick: &str,
+    ) -> Result<crate::types::SignedOrder, ClobError> {
+        validate_market_order(user_market_order, options_tick, None)?;
+        self.sign_market_order(exchange_address, user_market_order, options_tick)
+            .await
+    }
+
+    // `build_market_order` without validation, see `sign_order`.
+    pub(crate) async fn sign_market_order(
+        &self,
+        exchange_address: &str,
+        user_market_order: &crate::types::UserMarketOrder,
+        options_tick: &str,
     ) -> Result<crate::types::SignedOrder, ClobError> {
         let rc_map = rounding_config();
         let round_config = rc_map
             .get(options_tick)
-            .ok_or(ClobError::Other("invalid tick size".to_string()))?;
+            .ok_or(ClobError::Validation("invalid tick size".to_string()))?;
         let eoa_addr = self.signer.get_address().await?;
         let maker = self.funder_address.clone().unwrap_or(eoa_addr.clone());
 
         let order_data = build_market_order_creation_args(
             &eoa_addr,
             &maker,
-            self.signature_type.clone(),
+            self.signature_type,
             user_market_order,
             round_config,
         )
@@ -364,8 +532,8 @@ pub async fn build_market_order_creation_args(
         round_config,
     );
 
-    let maker_amount = parse_units(&ra.raw_maker_amt.to_string(), 6)?;
-    let taker_amount = parse_units(&ra.raw_taker_amt.to_string(), 6)?;
+    let maker_amount = parse_units(ra.raw_maker_amt, 6)?;
+    let taker_amount = parse_units(ra.raw_taker_amt, 6)?;
 
     let taker = if let Some(t) = &user_market_order.taker {
         if !t.is_empty() {
@@ -377,8 +545,7 @@ pub async fn build_market_order_creation_args(
         "0x0000000000000000000000000000000000000000".to_string()
     };
 
-    // fee_rate_bps 必填
-    let fee_rate_bps = user_market_order.fee_rate_bps.to_string();
+    let fee_rate_bps = user_market_order.fee_rate_bps.unwrap_or(0.0).to_string();
     let nonce = user_market_order
         .nonce
         .map(|v| v.to_string())
@@ -401,71 +568,259 @@ pub async fn build_market_order_creation_args(
 
 pub fn calculate_buy_market_price(
     positions: &[crate::types::OrderSummary],
-    amount_to_match: f64,
+    amount_to_match: Decimal,
     order_type: crate::types::OrderType,
-) -> Result<f64, ClobError> {
+) -> Result<Decimal, ClobError> {
     if positions.is_empty() {
-        return Err(ClobError::Other("no match".to_string()));
+        return Err(ClobError::Validation("no match".to_string()));
     }
-    let mut sum = 0.0;
+    let mut sum = Decimal::ZERO;
     for i in (0..positions.len()).rev() {
         let p = &positions[i];
-        let price = p.price.parse::<f64>().unwrap_or(0.0);
-        let size = p.size.parse::<f64>().unwrap_or(0.0);
+        let price = p.price.parse::<Decimal>().unwrap_or_default();
+        let size = p.size.parse::<Decimal>().unwrap_or_default();
         sum += size * price;
         if sum >= amount_to_match {
             return Ok(price);
         }
     }
     if let crate::types::OrderType::FOK = order_type {
-        return Err(ClobError::Other("no match".to_string()));
+        return Err(ClobError::Validation("no match".to_string()));
     }
-    Ok(positions[0].price.parse::<f64>().unwrap_or(0.0))
+    Ok(positions[0].price.parse::<Decimal>().unwrap_or_default())
 }
 
 pub fn calculate_sell_market_price(
     positions: &[crate::types::OrderSummary],
-    amount_to_match: f64,
+    amount_to_match: Decimal,
     order_type: crate::types::OrderType,
-) -> Result<f64, ClobError> {
+) -> Result<Decimal, ClobError> {
     if positions.is_empty() {
-        return Err(ClobError::Other("no match".to_string()));
+        return Err(ClobError::Validation("no match".to_string()));
     }
-    let mut sum = 0.0;
+    let mut sum = Decimal::ZERO;
     for i in (0..positions.len()).rev() {
         let p = &positions[i];
-        let price = p.price.parse::<f64>().unwrap_or(0.0);
-        let size = p.size.parse::<f64>().unwrap_or(0.0);
+        let price = p.price.parse::<Decimal>().unwrap_or_default();
+        let size = p.size.parse::<Decimal>().unwrap_or_default();
         sum += size;
         if sum >= amount_to_match {
             return Ok(price);
         }
     }
     if let crate::types::OrderType::FOK = order_type {
-        return Err(ClobError::Other("no match".to_string()));
+        return Err(ClobError::Validation("no match".to_string()));
     }
-    Ok(positions[0].price.parse::<f64>().unwrap_or(0.0))
+    Ok(positions[0].price.parse::<Decimal>().unwrap_or_default())
 }
 
 /// 基于外部订单簿快照计算市价单价格（纯函数，无 HTTP）
 pub fn compute_market_price_from_book(
     book: &crate::types::OrderBookSummary,
     side: crate::types::Side,
-    amount: f64,
+    amount: Decimal,
     order_type: crate::types::OrderType,
-) -> Result<f64, ClobError> {
+) -> Result<Decimal, ClobError> {
     match side {
         crate::types::Side::BUY => {
             if book.asks.is_empty() {
-                return Err(ClobError::Other("no match".to_string()));
+                return Err(ClobError::Validation("no match".to_string()));
             }
             calculate_buy_market_price(&book.asks, amount, order_type)
         }
         crate::types::Side::SELL => {
             if book.bids.is_empty() {
-                return Err(ClobError::Other("no match".to_string()));
+                return Err(ClobError::Validation("no match".to_string()));
             }
             calculate_sell_market_price(&book.bids, amount, order_type)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn raw_amounts_are_exact() {
+        let rc = &rounding_config()["0.01"];
+        // 0.57 * 13.33 = 7.5981 -> 7598100 maker units, no float drift
+        let ra = get_order_raw_amounts(Side::BUY, dec!(13.33), dec!(0.57), rc);
+        assert_eq!(ra.raw_maker_amt, dec!(7.5981));
+        assert_eq!(parse_units(ra.raw_maker_amt, 6).unwrap(), "7598100");
+        assert_eq!(parse_units(ra.raw_taker_amt, 6).unwrap(), "13330000");
+
+        let ra = get_market_order_raw_amounts(Side::BUY, dec!(10), dec!(0.3), rc);
+        assert_eq!(ra.raw_taker_amt, dec!(33.3333));
+        let ra = get_order_raw_amounts(Side::SELL, dec!(5.555), dec!(0.125), rc);
+        assert_eq!(ra.raw_maker_amt, dec!(5.55));
+        assert_eq!(ra.raw_taker_amt, dec!(0.7215));
+    }
+
+    fn limit(price: Decimal, size: Decimal, expiration: Option<u64>) -> UserOrder {
+        UserOrder {
+            token_id: "1".into(),
+            price,
+            size,
+            side: Side::BUY,
+            fee_rate_bps: None,
+            nonce: None,
+            expiration,
+            taker: None,
+        }
+    }
+
+    fn market(
+        side: Side,
+        price: Decimal,
+        amount: Decimal,
+        order_type: OrderType,
+    ) -> UserMarketOrder {
+        UserMarketOrder {
+            token_id: "1".into(),
+            price,
+            amount,
+            side,
+            fee_rate_bps: None,
+            nonce: None,
+            taker: None,
+            order_type,
+        }
+    }
+
+    // `now` depends on the clock; zero it so expected lists can be written out.
+    fn violations(result: Result<(), OrderValidationError>) -> Vec<OrderViolation> {
+        result
+            .err()
+            .map(|e| e.violations)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| match v {
+                OrderViolation::ExpirationInPast { expiration, .. } => {
+                    OrderViolation::ExpirationInPast { expiration, now: 0 }
+                }
+                v => v,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_every_violation() {
+        use OrderViolation::*;
+        let min = Some(dec!(5));
+        let future = unix_now() + 3600;
+        let cases = vec![
+            (
+                "valid GTD",
+                validate_order(
+                    &limit(dec!(0.5), dec!(10), Some(future)),
+                    Some(&OrderType::GTD),
+                    "0.01",
+                    min,
+                ),
+                vec![],
+            ),
+            (
+                "GTD without expiration",
+                validate_order(
+                    &limit(dec!(0.5), dec!(10), None),
+                    Some(&OrderType::GTD),
+                    "0.01",
+                    min,
+                ),
+                vec![MissingExpiration],
+            ),
+            (
+                "expired",
+                validate_order(&limit(dec!(0.5), dec!(10), Some(1)), None, "0.01", min),
+                vec![ExpirationInPast {
+                    expiration: 1,
+                    now: 0,
+                }],
+            ),
+            (
+                "FAK limit order",
+                validate_order(
+                    &limit(dec!(0.5), dec!(10), None),
+                    Some(&OrderType::FAK),
+                    "0.01",
+                    min,
+                ),
+                vec![MarketOnlyOrderType {
+                    order_type: OrderType::FAK,
+                }],
+            ),
+            (
+                "maker amount rounds to zero",
+                validate_order(&limit(dec!(0.0004), dec!(2), None), None, "0.001", min),
+                vec![
+                    PriceOutOfRange {
+                        price: dec!(0.0004),
+                        min: dec!(0.001),
+                        max: dec!(0.999),
+                    },
+                    PriceNotOnTick {
+                        price: dec!(0.0004),
+                        tick_size: dec!(0.001),
+                    },
+                    SizeBelowMinimum {
+                        size: dec!(2),
+                        min_order_size: dec!(5),
+                    },
+                    ZeroAmount {
+                        maker_amount: "0".into(),
+                        taker_amount: "2000000".into(),
+                    },
+                ],
+            ),
+            (
+                "unsupported tick size",
+                validate_order(&limit(dec!(0.5), dec!(10), None), None, "0.5", min),
+                vec![UnsupportedTickSize("0.5".into())],
+            ),
+            (
+                "market buy below minimum shares",
+                validate_market_order(
+                    &market(Side::BUY, dec!(0.5), dec!(2), OrderType::FOK),
+                    "0.01",
+                    min,
+                ),
+                // $2 at 0.5 buys 4 shares
+                vec![SizeBelowMinimum {
+                    size: dec!(4),
+                    min_order_size: dec!(5),
+                }],
+            ),
+            (
+                "market buy above minimum shares",
+                validate_market_order(
+                    &market(Side::BUY, dec!(0.25), dec!(2), OrderType::FAK),
+                    "0.01",
+                    min,
+                ),
+                vec![],
+            ),
+            (
+                "GTC market sell",
+                validate_market_order(
+                    &market(Side::SELL, dec!(0.5), dec!(2), OrderType::GTC),
+                    "0.01",
+                    min,
+                ),
+                vec![
+                    SizeBelowMinimum {
+                        size: dec!(2),
+                        min_order_size: dec!(5),
+                    },
+                    LimitOnlyOrderType {
+                        order_type: OrderType::GTC,
+                    },
+                ],
+            ),
+        ];
+        for (name, result, expected) in cases {
+            assert_eq!(violations(result), expected, "{}", name);
+        }
+    }
+}
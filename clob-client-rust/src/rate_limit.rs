@@ -0,0 +1,387 @@
+use crate::errors::ClobError;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Buckets that share a rate budget on the exchange side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    /// Books, prices, midpoints, spreads, markets, tick size / neg risk / fee rate lookups.
+    MarketData,
+    /// `POST /order`, `POST /orders`.
+    Orders,
+    /// `DELETE /order`, `DELETE /orders`, `/cancel-all`, `/cancel-market-orders`.
+    Cancels,
+    /// `/auth/*` (API key creation, derivation, listing).
+    Auth,
+    /// Everything else (user data, notifications, balances, rewards...).
+    Other,
+}
+
+impl EndpointGroup {
+    pub const ALL: [EndpointGroup; 5] = [
+        EndpointGroup::MarketData,
+        EndpointGroup::Orders,
+        EndpointGroup::Cancels,
+        EndpointGroup::Auth,
+        EndpointGroup::Other,
+    ];
+
+    /// Classify a request by method and URL (full URL or bare path).
+    pub fn classify(method: &str, url: &str) -> Self {
+        let path = url_path(url);
+        if path.starts_with("/auth") {
+            return EndpointGroup::Auth;
+        }
+        match path {
+            "/cancel-all" | "/cancel-market-orders" => return EndpointGroup::Cancels,
+            "/order" | "/orders" => {
+                return if method.eq_ignore_ascii_case("DELETE") {
+                    EndpointGroup::Cancels
+                } else {
+                    EndpointGroup::Orders
+                };
+            }
+            _ => {}
+        }
+        const MARKET_DATA: [&str; 19] = [
+            "/book",
+            "/books",
+            "/midpoint",
+            "/midpoints",
+            "/price",
+            "/prices",
+            "/spread",
+            "/spreads",
+            "/last-trade-price",
+            "/last-trades-prices",
+            "/tick-size",
+            "/neg-risk",
+            "/fee-rate",
+            "/prices-history",
+            "/markets",
+            "/sampling-markets",
+            "/sampling-simplified-markets",
+            "/simplified-markets",
+            "/time",
+        ];
+        if MARKET_DATA.contains(&path) || path.starts_with("/markets/") {
+            EndpointGroup::MarketData
+        } else {
+            EndpointGroup::Other
+        }
+    }
+}
+
+// Strip scheme, host and query string.
+fn url_path(url: &str) -> &str {
+    let rest = match url.find("://") {
+        Some(i) => {
+            let after = &url[i + 3..];
+            after.find('/').map(|j| &after[j..]).unwrap_or("/")
+        }
+        None => url,
+    };
+    rest.split('?').next().unwrap_or(rest)
+}
+
+/// Token bucket parameters: up to `burst` requests at once, refilled at `per_second`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    burst: u32,
+    per_second: f64,
+}
+
+impl RateLimit {
+    /// A `burst` of 0 is raised to 1 so the bucket can ever hand out a token.
+    ///
+    /// `per_second` must be positive and finite, otherwise the bucket would never refill.
+    pub fn new(burst: u32, per_second: f64) -> Result<Self, ClobError> {
+        if !(per_second > 0.0 && per_second.is_finite()) {
+            return Err(ClobError::Config(format!(
+                "rate limit refill rate must be positive and finite, got {}",
+                per_second
+            )));
+        }
+        Ok(Self {
+            burst: burst.max(1),
+            per_second,
+        })
+    }
+
+    /// `requests` per `window`, with the whole window's budget usable as a burst.
+    pub fn per_window(requests: u32, window: Duration) -> Result<Self, ClobError> {
+        if window.is_zero() {
+            return Err(ClobError::Config(
+                "rate limit window must not be zero".to_string(),
+            ));
+        }
+        Self::new(requests, requests.max(1) as f64 / window.as_secs_f64())
+    }
+
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    pub fn per_second(&self) -> f64 {
+        self.per_second
+    }
+}
+
+/// Budgets per endpoint group. `None` means the group is not limited client-side.
+///
+/// Defaults sit below the documented exchange limits so that a single process stays clear of 429s.
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    pub market_data: Option<RateLimit>,
+    pub orders: Option<RateLimit>,
+    pub cancels: Option<RateLimit>,
+    pub auth: Option<RateLimit>,
+    pub other: Option<RateLimit>,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        let per_10s = |n| {
+            RateLimit::per_window(n, Duration::from_secs(10)).expect("default rate limit is valid")
+        };
+        Self {
+            market_data: Some(per_10s(150)),
+            orders: Some(per_10s(200)),
+            cancels: Some(per_10s(200)),
+            auth: Some(per_10s(50)),
+            other: Some(per_10s(100)),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// No client-side limiting at all.
+    pub fn unlimited() -> Self {
+        Self {
+            market_data: None,
+            orders: None,
+            cancels: None,
+            auth: None,
+            other: None,
+        }
+    }
+
+    pub fn with_limit(mut self, group: EndpointGroup, limit: Option<RateLimit>) -> Self {
+        match group {
+            EndpointGroup::MarketData => self.market_data = limit,
+            EndpointGroup::Orders => self.orders = limit,
+            EndpointGroup::Cancels => self.cancels = limit,
+            EndpointGroup::Auth => self.auth = limit,
+            EndpointGroup::Other => self.other = limit,
+        }
+        self
+    }
+
+    pub fn limit(&self, group: EndpointGroup) -> Option<RateLimit> {
+        match group {
+            EndpointGroup::MarketData => self.market_data,
+            EndpointGroup::Orders => self.orders,
+            EndpointGroup::Cancels => self.cancels,
+            EndpointGroup::Auth => self.auth,
+            EndpointGroup::Other => self.other,
+        }
+    }
+}
+
+/// Current state of one group's bucket, for monitoring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupBudget {
+    pub group: EndpointGroup,
+    pub limit: RateLimit,
+    /// Tokens available right now (fractional while refilling).
+    pub available: f64,
+    /// Requests currently waiting for a token.
+    pub queued: usize,
+}
+
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+struct Bucket {
+    limit: RateLimit,
+    state: Mutex<BucketState>,
+    // FIFO turnstile: waiters are served in arrival order.
+    queue: tokio::sync::Mutex<()>,
+    queued: AtomicUsize,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            state: Mutex::new(BucketState {
+                tokens: limit.burst as f64,
+                last_refill: Instant::now(),
+            }),
+            queue: tokio::sync::Mutex::new(()),
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    fn refill(&self, st: &mut BucketState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(st.last_refill).as_secs_f64();
+        st.tokens = (st.tokens + elapsed * self.limit.per_second).min(self.limit.burst as f64);
+        st.last_refill = now;
+    }
+
+    // Take a token, or report how long until one is available.
+    fn try_take(&self) -> Result<(), Duration> {
+        let mut st = self.state.lock().unwrap();
+        self.refill(&mut st);
+        if st.tokens >= 1.0 {
+            st.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - st.tokens) / self.limit.per_second,
+            ))
+        }
+    }
+
+    async fn acquire(&self) {
+        // decrement on drop so a cancelled request does not stay counted as queued
+        struct Queued<'a>(&'a AtomicUsize);
+        impl Drop for Queued<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+        self.queued.fetch_add(1, Ordering::SeqCst);
+        let _queued = Queued(&self.queued);
+        let _turn = self.queue.lock().await;
+        while let Err(wait) = self.try_take() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn available(&self) -> f64 {
+        let mut st = self.state.lock().unwrap();
+        self.refill(&mut st);
+        st.tokens
+    }
+}
+
+/// Client-side token-bucket limiter. Requests over budget wait for a token instead of being
+/// sent into a 429. Share one limiter (via `Arc`) between clients that use the same API key.
+pub struct RateLimiter {
+    buckets: HashMap<EndpointGroup, Bucket>,
+}
+
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("budget", &self.budget())
+            .finish()
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitConfig::default())
+    }
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        let buckets = EndpointGroup::ALL
+            .iter()
+            .filter_map(|g| config.limit(*g).map(|l| (*g, Bucket::new(l))))
+            .collect();
+        Self { buckets }
+    }
+
+    /// Wait until a request for `group` may be sent.
+    pub async fn acquire(&self, group: EndpointGroup) {
+        if let Some(b) = self.buckets.get(&group) {
+            b.acquire().await;
+        }
+    }
+
+    /// Snapshot of every limited group.
+    pub fn budget(&self) -> Vec<GroupBudget> {
+        EndpointGroup::ALL
+            .iter()
+            .filter_map(|g| self.group_budget(*g))
+            .collect()
+    }
+
+    pub fn group_budget(&self, group: EndpointGroup) -> Option<GroupBudget> {
+        self.buckets.get(&group).map(|b| GroupBudget {
+            group,
+            limit: b.limit,
+            available: b.available(),
+            queued: b.queued.load(Ordering::SeqCst),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_endpoints() {
+        let host = "https://clob.polymarket.com";
+        let c = |m: &str, p: &str| EndpointGroup::classify(m, &format!("{}{}", host, p));
+        assert_eq!(c("GET", "/book?token_id=1"), EndpointGroup::MarketData);
+        assert_eq!(c("POST", "/prices"), EndpointGroup::MarketData);
+        assert_eq!(c("GET", "/markets/0xabc"), EndpointGroup::MarketData);
+        assert_eq!(c("POST", "/orders"), EndpointGroup::Orders);
+        assert_eq!(c("DELETE", "/order"), EndpointGroup::Cancels);
+        assert_eq!(c("DELETE", "/cancel-all"), EndpointGroup::Cancels);
+        assert_eq!(c("GET", "/auth/derive-api-key"), EndpointGroup::Auth);
+        assert_eq!(c("GET", "/data/orders"), EndpointGroup::Other);
+    }
+
+    #[tokio::test]
+    async fn queues_once_burst_is_spent() {
+        let limiter = RateLimiter::new(RateLimitConfig::unlimited().with_limit(
+            EndpointGroup::Orders,
+            Some(RateLimit::new(2, 20.0).unwrap()),
+        ));
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire(EndpointGroup::Orders).await;
+        }
+        // third request had to wait ~50ms for a refill
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        assert!(limiter.group_budget(EndpointGroup::MarketData).is_none());
+        let budget = limiter.group_budget(EndpointGroup::Orders).unwrap();
+        assert!(budget.available < 1.0);
+        assert_eq!(budget.queued, 0);
+    }
+
+    #[tokio::test]
+    async fn zero_burst_still_hands_out_tokens() {
+        assert_eq!(RateLimit::new(0, 50.0).unwrap().burst(), 1);
+        let limit = RateLimit::per_window(0, Duration::from_secs(1)).unwrap();
+        assert_eq!(limit.burst(), 1);
+        let limiter = RateLimiter::new(
+            RateLimitConfig::unlimited().with_limit(EndpointGroup::Orders, Some(limit)),
+        );
+        tokio::time::timeout(Duration::from_secs(1), async {
+            for _ in 0..2 {
+                limiter.acquire(EndpointGroup::Orders).await;
+            }
+        })
+        .await
+        .expect("acquire never completed");
+
+        for rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let err = RateLimit::new(10, rate).unwrap_err();
+            assert!(matches!(err, ClobError::Config(m) if m.contains("refill rate")));
+        }
+        let err = RateLimit::per_window(10, Duration::ZERO).unwrap_err();
+        assert!(matches!(err, ClobError::Config(m) if m.contains("window")));
+    }
+}
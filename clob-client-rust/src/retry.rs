@@ -0,0 +1,175 @@
+use crate::errors::ClobError;
+use crate::http_helpers::GET;
+use crate::transport::HttpResponse;
+use rand::Rng;
+use std::time::Duration;
+
+/// Retry policy applied by `ClobClient` around every transport call.
+///
+/// GET requests are retried by default. POST/DELETE carry signed orders and cancels, so they are
+/// only retried when `retry_non_idempotent` is set explicitly; otherwise a dropped connection
+/// could re-post an order that the exchange already accepted.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one; `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled on each further attempt.
+    pub base_delay: Duration,
+    /// Upper bound for the computed backoff and for a server-provided `Retry-After`.
+    pub max_delay: Duration,
+    /// Randomize each delay in `[delay/2, delay]` to avoid synchronized retries.
+    pub jitter: bool,
+    /// Response status codes that are worth retrying.
+    pub retry_statuses: Vec<u16>,
+    /// Also retry POST/DELETE requests. Off by default.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+            retry_statuses: vec![429, 500, 502, 503, 504],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_delays(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_retry_statuses(mut self, statuses: Vec<u16>) -> Self {
+        self.retry_statuses = statuses;
+        self
+    }
+
+    /// Opt in to retrying POST/DELETE. Only enable this if re-submitting the same signed
+    /// payload is acceptable for your use case.
+    pub fn with_retry_non_idempotent(mut self, enabled: bool) -> Self {
+        self.retry_non_idempotent = enabled;
+        self
+    }
+
+    /// Exponential backoff for the given retry number (1 = first retry), with optional jitter.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(1u32 << retry.saturating_sub(1).min(16));
+        let delay = exp.min(self.max_delay);
+        if self.jitter && !delay.is_zero() {
+            let millis = delay.as_millis() as u64;
+            Duration::from_millis(rand::thread_rng().gen_range(millis / 2..=millis))
+        } else {
+            delay
+        }
+    }
+
+    /// Decide whether the outcome of attempt number `attempt` (1-based) should be retried, and
+    /// after how long. Returns `None` to hand the result back to the caller.
+    pub fn next_delay(
+        &self,
+        method: &str,
+        attempt: u32,
+        outcome: &Result<HttpResponse, ClobError>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        if method != GET && !self.retry_non_idempotent {
+            return None;
+        }
+        match outcome {
+            Err(e) if e.is_retryable() => Some(self.backoff(attempt)),
+            Err(_) => None,
+            Ok(resp) if self.retry_statuses.contains(&resp.status) => {
+                match retry_after(resp) {
+                    // The server asked for longer than we are willing to wait: give up now.
+                    Some(d) if d > self.max_delay => None,
+                    Some(d) => Some(d),
+                    None => Some(self.backoff(attempt)),
+                }
+            }
+            Ok(_) => None,
+        }
+    }
+}
+
+/// `Retry-After` in delta-seconds form. HTTP-date values are ignored.
+fn retry_after(resp: &HttpResponse) -> Option<Duration> {
+    if resp.status != 429 && resp.status != 503 {
+        return None;
+    }
+    resp.header("retry-after")
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_helpers::POST;
+    use std::collections::HashMap;
+
+    fn resp(status: u16, retry_after: Option<&str>) -> Result<HttpResponse, ClobError> {
+        let mut headers = HashMap::new();
+        if let Some(v) = retry_after {
+            headers.insert("retry-after".to_string(), v.to_string());
+        }
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: String::new(),
+        })
+    }
+
+    #[test]
+    fn retries_gets_only_and_honours_retry_after() {
+        let p = RetryPolicy::default().with_jitter(false);
+        assert_eq!(
+            p.next_delay(GET, 1, &resp(502, None)),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            p.next_delay(GET, 2, &resp(502, None)),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(p.next_delay(GET, 3, &resp(502, None)), None);
+        assert_eq!(p.next_delay(GET, 1, &resp(400, None)), None);
+        assert_eq!(
+            p.next_delay(GET, 1, &resp(429, Some("2"))),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(p.next_delay(GET, 1, &resp(429, Some("60"))), None);
+
+        // signed payloads are never re-sent unless explicitly enabled
+        let transport_err = Err(ClobError::Transport("connection reset".into()));
+        assert_eq!(p.next_delay(POST, 1, &transport_err), None);
+        let p = p.with_retry_non_idempotent(true);
+        assert!(p.next_delay(POST, 1, &transport_err).is_some());
+    }
+}
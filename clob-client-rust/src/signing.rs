@@ -1,13 +1,16 @@
 use crate::errors::ClobError;
 use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose};
+use builder_signing_sdk_rs::AbstractSigner;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 
 type HmacSha256 = Hmac<Sha256>;
 
-/// Trait representing a signer that can perform EIP-712 typed data signing.
-/// In a full integration this will be implemented by an ethers-rs signer wrapper.
+/// EIP-712 signing as used by the order and header builders: domain, types and message are
+/// passed as separate JSON strings (TypeScript `_signTypedData(domain, types, value)` layout).
+///
+/// Implemented for every `AbstractSigner`; custody backends implement that trait instead.
 #[async_trait]
 pub trait Eip712Signer: Send + Sync {
     /// Return the address for this signer (e.g. Ethereum address hex)
@@ -23,22 +26,61 @@ pub trait Eip712Signer: Send + Sync {
     ) -> Result<String, ClobError>;
 }
 
+#[async_trait]
+impl<T: AbstractSigner + ?Sized> Eip712Signer for T {
+    async fn get_address(&self) -> Result<String, ClobError> {
+        Ok(AbstractSigner::address(self).await?)
+    }
+
+    async fn sign_typed_data(
+        &self,
+        domain: &str,
+        types: &str,
+        value: &str,
+    ) -> Result<String, ClobError> {
+        let parse = |what: &str, json: &str| {
+            serde_json::from_str::<serde_json::Value>(json)
+                .map_err(|e| ClobError::Signing(format!("invalid {} json: {}", what, e)))
+        };
+        let types_val = parse("types", types)?;
+        // primaryType: prefer ClobAuth / Order, otherwise the first declared type
+        let primary_type = types_val
+            .as_object()
+            .and_then(|map| {
+                ["ClobAuth", "Order"]
+                    .into_iter()
+                    .find(|k| map.contains_key(*k))
+                    .map(str::to_string)
+                    .or_else(|| map.keys().next().cloned())
+            })
+            .unwrap_or_else(|| "Order".to_string());
+
+        // IMPORTANT: Do NOT inject EIP712Domain into types here - keep types exactly as the caller passed
+        // (TypeScript SDK sends types without an explicit EIP712Domain key).
+        let full = serde_json::json!({
+            "types": types_val,
+            "domain": parse("domain", domain)?,
+            "primaryType": primary_type,
+            "message": parse("message", value)?,
+        });
+        tracing::trace!(typed_data = %full, "signing typed data");
+
+        let sig = AbstractSigner::sign_typed_data(self, &full).await?;
+        tracing::trace!(%sig, "typed-data signature");
+        Ok(sig)
+    }
+}
+
 /// Build the canonical Polymarket CLOB EIP712 signature.
 /// This function delegates to the provided `Eip712Signer` implementation.
-pub async fn build_clob_eip712_signature<S: Eip712Signer>(
+pub async fn build_clob_eip712_signature<S: Eip712Signer + ?Sized>(
     signer: &S,
     chain_id: i64,
     timestamp: u64,
     nonce: u64,
 ) -> Result<String, ClobError> {
     let address = signer.get_address().await?;
-    if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-        eprintln!(
-            "[EIP712 DEBUG] signer.get_address() => '{}' (len={})",
-            address,
-            address.len()
-        );
-    }
+    tracing::trace!(%address, "building ClobAuth signature");
     let ts = format!("{}", timestamp);
 
     // Build minimal canonical domain/types/value as JSON strings. The signer
@@ -82,9 +124,7 @@ pub async fn build_clob_eip712_signature<S: Eip712Signer>(
     if !sig.starts_with("0x") {
         sig = format!("0x{}", sig);
     }
-    if std::env::var("CLOB_DEBUG_FULL").is_ok() || std::env::var("CLOB_DEBUG_RAW").is_ok() {
-        eprintln!("[EIP712 DEBUG] final signature (0x-prefixed): {}", sig);
-    }
+    tracing::trace!(%sig, "ClobAuth signature");
     Ok(sig)
 }
 
@@ -113,10 +153,10 @@ pub fn build_poly_hmac_signature(
     };
     let secret = general_purpose::STANDARD
         .decode(padded.as_bytes())
-        .map_err(|e| ClobError::Other(format!("invalid base64(url) secret: {}", e)))?;
+        .map_err(|e| ClobError::Signing(format!("invalid base64(url) secret: {}", e)))?;
 
     let mut mac = HmacSha256::new_from_slice(&secret)
-        .map_err(|e| ClobError::Other(format!("hmac init error: {}", e)))?;
+        .map_err(|e| ClobError::Signing(format!("hmac init error: {}", e)))?;
     mac.update(message.as_bytes());
     let result = mac.finalize().into_bytes();
 
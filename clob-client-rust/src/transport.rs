@@ -0,0 +1,101 @@
+use crate::errors::ClobError;
+use crate::http_helpers::HttpClientConfig;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// A fully prepared HTTP request: headers are final, the body is already serialized.
+#[derive(Debug, Clone, Default)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub query: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+/// Raw response as seen by the client: status code, headers (lower-cased names) and body text.
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Case-insensitive header lookup.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(|v| v.as_str())
+    }
+}
+
+/// Request in, status + body out. `ClobClient` sends everything through this trait, so tests
+/// can plug in an in-process fake exchange, and recording/replay layers can wrap the default.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, ClobError>;
+}
+
+/// Default transport backed by a long-lived `reqwest::Client`.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    pub fn from_config(cfg: &HttpClientConfig) -> Result<Self, ClobError> {
+        Ok(Self::new(cfg.build()?))
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, ClobError> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes()).map_err(|e| {
+            ClobError::Other(format!("unsupported method {}: {}", request.method, e))
+        })?;
+        let mut req = self.client.request(method, &request.url);
+        for (k, v) in request.headers.iter() {
+            req = req.header(k, v);
+        }
+        if !request.query.is_empty() {
+            req = req.query(&request.query);
+        }
+        if let Some(body) = request.body {
+            req = req.body(body);
+        }
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| ClobError::Transport(format!("HTTP request failed: {}", e)))?;
+        let status = resp.status().as_u16();
+        let mut headers = HashMap::new();
+        for (k, v) in resp.headers().iter() {
+            if let Ok(s) = v.to_str() {
+                headers.insert(k.as_str().to_ascii_lowercase(), s.to_string());
+            }
+        }
+        let body = resp
+            .text()
+            .await
+            .map_err(|e| ClobError::Transport(format!("Failed to read response body: {}", e)))?;
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
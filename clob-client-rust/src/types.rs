@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::de::Deserializer;
 use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
@@ -96,16 +97,21 @@ pub struct OrderSummary {
     pub size: String,
 }
 
+/// `/book` response. Field names and order follow the server JSON, which the book hash is
+/// computed over.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
 pub struct OrderBookSummary {
     pub market: String,
+    #[serde(alias = "assetId")]
     pub asset_id: String,
     pub timestamp: String,
     pub bids: Vec<OrderSummary>,
     pub asks: Vec<OrderSummary>,
+    #[serde(alias = "minOrderSize")]
     pub min_order_size: String,
+    #[serde(alias = "tickSize")]
     pub tick_size: String,
+    #[serde(alias = "negRisk")]
     pub neg_risk: bool,
     pub hash: String,
 }
@@ -114,20 +120,15 @@ pub struct OrderBookSummary {
 /// - EOA: Standard Externally Owned Account (default)
 /// - PolyProxy: Polymarket Proxy Wallet
 /// - PolyGnosisSafe: Gnosis Safe Multisig
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[repr(u8)]
 pub enum SignatureType {
+    #[default]
     EOA = 0,
     PolyProxy = 1,
     PolyGnosisSafe = 2,
 }
 
-impl Default for SignatureType {
-    fn default() -> Self {
-        SignatureType::EOA
-    }
-}
-
 impl From<SignatureType> for u8 {
     fn from(sig_type: SignatureType) -> Self {
         sig_type as u8
@@ -281,11 +282,11 @@ pub const CHAIN_AMOY: i32 = 80002;
 #[serde(rename_all = "camelCase")]
 pub struct UserOrder {
     pub token_id: String,
-    pub price: f64,
-    pub size: f64,
+    pub price: Decimal,
+    pub size: Decimal,
     pub side: Side,
-    // 改为必填: fee_rate_bps 由外部传入，不再在创建流程中自动获取
-    pub fee_rate_bps: f64,
+    // 可选: 未指定时由 create_order 按 token 查询市场基础费率
+    pub fee_rate_bps: Option<f64>,
     pub nonce: Option<u64>,
     pub expiration: Option<u64>,
     pub taker: Option<String>,
@@ -296,11 +297,11 @@ pub struct UserOrder {
 pub struct UserMarketOrder {
     pub token_id: String,
     // 改为必填: 市价单价格需由外部计算并传入
-    pub price: f64,
-    pub amount: f64,
+    pub price: Decimal,
+    pub amount: Decimal,
     pub side: Side,
-    // 改为必填: 由外部传入
-    pub fee_rate_bps: f64,
+    // 可选: 未指定时使用市场基础费率
+    pub fee_rate_bps: Option<f64>,
     pub nonce: Option<u64>,
     pub taker: Option<String>,
     // order_type 改为必填: 市价单必须明确 FOK/FAK (或未来支持的其他类型)
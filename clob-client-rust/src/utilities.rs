@@ -1,6 +1,8 @@
 use crate::types::{NewOrder, NewOrderData, OrderBookSummary, OrderType, SignedOrder};
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde_json::to_string;
 use sha1::{Digest, Sha1};
+use std::str::FromStr;
 
 /// Convert a SignedOrder to NewOrder format for posting to the API
 /// This matches the TypeScript orderToJson function
@@ -34,40 +36,31 @@ pub fn order_to_json(
     }
 }
 
-/// Round normally to given decimals
-pub fn round_normal(num: f64, decimals: u32) -> f64 {
+/// Round normally to given decimals (half away from zero)
+pub fn round_normal(num: Decimal, decimals: u32) -> Decimal {
     if decimal_places(num) <= decimals {
         return num;
     }
-    let factor = 10f64.powi(decimals as i32);
-    ((num + f64::EPSILON) * factor).round() / factor
+    num.round_dp_with_strategy(decimals, RoundingStrategy::MidpointAwayFromZero)
 }
 
-pub fn round_down(num: f64, decimals: u32) -> f64 {
+pub fn round_down(num: Decimal, decimals: u32) -> Decimal {
     if decimal_places(num) <= decimals {
         return num;
     }
-    let factor = 10f64.powi(decimals as i32);
-    (num * factor).floor() / factor
+    num.round_dp_with_strategy(decimals, RoundingStrategy::ToNegativeInfinity)
 }
 
-pub fn round_up(num: f64, decimals: u32) -> f64 {
+pub fn round_up(num: Decimal, decimals: u32) -> Decimal {
     if decimal_places(num) <= decimals {
         return num;
     }
-    let factor = 10f64.powi(decimals as i32);
-    (num * factor).ceil() / factor
+    num.round_dp_with_strategy(decimals, RoundingStrategy::ToPositiveInfinity)
 }
 
-pub fn decimal_places(num: f64) -> u32 {
-    if num.fract() == 0.0 {
-        return 0;
-    }
-    let s = format!("{}", num);
-    match s.split('.').nth(1) {
-        Some(frac) => frac.len() as u32,
-        None => 0,
-    }
+/// Number of significant fractional digits (trailing zeros ignored).
+pub fn decimal_places(num: Decimal) -> u32 {
+    num.normalize().scale()
 }
 
 /// Generates a SHA1 hash of the provided orderbook JSON. Returns the hex string.
@@ -84,15 +77,15 @@ pub fn generate_orderbook_summary_hash(orderbook: &OrderBookSummary) -> String {
 }
 
 pub fn is_tick_size_smaller(a: &str, b: &str) -> bool {
-    match (a.parse::<f64>(), b.parse::<f64>()) {
+    match (Decimal::from_str(a), Decimal::from_str(b)) {
         (Ok(aa), Ok(bb)) => aa < bb,
         _ => false,
     }
 }
 
-pub fn price_valid(price: f64, tick_size: &str) -> bool {
-    match tick_size.parse::<f64>() {
-        Ok(ts) => price >= ts && price <= 1.0 - ts,
+pub fn price_valid(price: Decimal, tick_size: &str) -> bool {
+    match Decimal::from_str(tick_size) {
+        Ok(ts) => price >= ts && price <= Decimal::ONE - ts,
         Err(_) => false,
     }
 }
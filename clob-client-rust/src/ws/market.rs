@@ -0,0 +1,316 @@
+use super::{Channel, WsConfig, WsHandle, WsStream};
+use crate::types::{OrderSummary, Side};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Full order book snapshot, sent on subscribe and whenever a trade touches the book.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BookEvent {
+    pub asset_id: String,
+    pub market: String,
+    #[serde(alias = "buys")]
+    pub bids: Vec<OrderSummary>,
+    #[serde(alias = "sells")]
+    pub asks: Vec<OrderSummary>,
+    #[serde(default)]
+    pub timestamp: String,
+    #[serde(default)]
+    pub hash: String,
+}
+
+/// One price level update inside a `price_change` event. `size` is the new aggregate size at
+/// `price`; `"0"` removes the level.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceChange {
+    pub asset_id: String,
+    pub price: String,
+    pub size: String,
+    pub side: Side,
+    #[serde(default)]
+    pub hash: Option<String>,
+    #[serde(default)]
+    pub best_bid: Option<String>,
+    #[serde(default)]
+    pub best_ask: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceChangeEvent {
+    pub market: String,
+    pub price_changes: Vec<PriceChange>,
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TickSizeChangeEvent {
+    pub asset_id: String,
+    pub market: String,
+    pub old_tick_size: String,
+    pub new_tick_size: String,
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LastTradePriceEvent {
+    pub asset_id: String,
+    pub market: String,
+    pub price: String,
+    pub side: Side,
+    pub size: String,
+    #[serde(default)]
+    pub fee_rate_bps: String,
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+/// Items yielded by `MarketStream`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarketEvent {
+    Book(BookEvent),
+    PriceChange(PriceChangeEvent),
+    TickSizeChange(TickSizeChangeEvent),
+    LastTradePrice(LastTradePriceEvent),
+    /// The socket was re-established and subscriptions replayed. Events may have been missed
+    /// in between, so local books should be resynced (a fresh `Book` follows for each asset).
+    Reconnected,
+}
+
+pub type MarketStream = WsStream<MarketEvent>;
+
+/// Client for the public market channel.
+///
+/// ```no_run
+/// # async fn demo() {
+/// use futures_util::StreamExt;
+/// use clob_client_rust::ws::{MarketChannel, MarketEvent};
+///
+/// let (handle, mut events) = MarketChannel::connect_default(vec!["<token id>".to_string()]);
+/// while let Some(ev) = events.next().await {
+///     if let MarketEvent::Book(book) = ev {
+///         println!("{} bids={} asks={}", book.asset_id, book.bids.len(), book.asks.len());
+///     }
+/// }
+/// handle.close().ok();
+/// # }
+/// ```
+pub struct MarketChannel;
+
+impl MarketChannel {
+    /// Spawn the connection task and subscribe to `asset_ids`. Connecting happens in the
+    /// background; failures are retried with backoff.
+    pub fn connect(config: WsConfig, asset_ids: Vec<String>) -> (WsHandle, MarketStream) {
+        super::spawn(config, MarketProtocol, asset_ids)
+    }
+
+    /// `connect` against the production endpoint with default settings.
+    pub fn connect_default(asset_ids: Vec<String>) -> (WsHandle, MarketStream) {
+        Self::connect(WsConfig::new(super::WS_MARKET_URL), asset_ids)
+    }
+}
+
+struct MarketProtocol;
+
+impl Channel for MarketProtocol {
+    type Event = MarketEvent;
+
+    fn initial_message(&self, ids: &[String]) -> String {
+        json!({ "assets_ids": ids, "type": "market" }).to_string()
+    }
+
+    fn update_message(&self, ids: &[String], subscribe: bool) -> String {
+        let operation = if subscribe {
+            "subscribe"
+        } else {
+            "unsubscribe"
+        };
+        json!({ "assets_ids": ids, "operation": operation }).to_string()
+    }
+
+    fn parse(&self, text: &str) -> Vec<MarketEvent> {
+        parse_market_message(text)
+    }
+
+    fn reconnected(&self) -> MarketEvent {
+        MarketEvent::Reconnected
+    }
+}
+
+/// Parse a market channel frame: a single event object or an array of them. Unknown event
+/// types and malformed entries are skipped.
+pub fn parse_market_message(text: &str) -> Vec<MarketEvent> {
+    let value: Value = match serde_json::from_str(text) {
+        Ok(v) => v,
+        Err(e) => {
+            tracing::debug!(error = %e, text, "ignoring non-JSON market message");
+            return Vec::new();
+        }
+    };
+    let items = match value {
+        Value::Array(items) => items,
+        other => vec![other],
+    };
+    items.into_iter().filter_map(parse_market_event).collect()
+}
+
+fn parse_market_event(value: Value) -> Option<MarketEvent> {
+    let kind = value.get("event_type")?.as_str()?.to_string();
+    let parsed = match kind.as_str() {
+        "book" => serde_json::from_value(value).map(MarketEvent::Book),
+        "price_change" => serde_json::from_value(value).map(MarketEvent::PriceChange),
+        "tick_size_change" => serde_json::from_value(value).map(MarketEvent::TickSizeChange),
+        "last_trade_price" => serde_json::from_value(value).map(MarketEvent::LastTradePrice),
+        _ => {
+            tracing::trace!(event_type = %kind, "ignoring market event");
+            return None;
+        }
+    };
+    parsed
+        .map_err(|e| tracing::debug!(event_type = %kind, error = %e, "malformed market event"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{SinkExt, StreamExt};
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    async fn next_text(
+        ws: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+    ) -> Value {
+        loop {
+            match ws.next().await.unwrap().unwrap() {
+                Message::Text(t) if t != "PING" => return serde_json::from_str(&t).unwrap(),
+                _ => {}
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn streams_events_and_resubscribes_after_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            // first connection: initial subscribe, one runtime subscribe, then drop
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            assert_eq!(
+                next_text(&mut ws).await,
+                json!({"assets_ids": ["1"], "type": "market"})
+            );
+            ws.send(Message::Text(
+                json!([{
+                    "event_type": "book", "asset_id": "1", "market": "0xm",
+                    "bids": [{"price": "0.48", "size": "10"}],
+                    "asks": [{"price": "0.52", "size": "5"}],
+                    "timestamp": "1", "hash": "0xh"
+                }])
+                .to_string(),
+            ))
+            .await
+            .unwrap();
+            assert_eq!(
+                next_text(&mut ws).await,
+                json!({"assets_ids": ["2"], "operation": "subscribe"})
+            );
+            drop(ws);
+
+            // second connection: every subscription is replayed
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            assert_eq!(
+                next_text(&mut ws).await,
+                json!({"assets_ids": ["1", "2"], "type": "market"})
+            );
+            ws.send(Message::Text(
+                json!({
+                    "event_type": "last_trade_price", "asset_id": "2", "market": "0xm",
+                    "price": "0.5", "side": "BUY", "size": "3", "fee_rate_bps": "0",
+                    "timestamp": "2"
+                })
+                .to_string(),
+            ))
+            .await
+            .unwrap();
+            let _ = ws.next().await;
+        });
+
+        let config = WsConfig::new(url)
+            .with_reconnect_delay(Duration::from_millis(10), Duration::from_millis(50));
+        let (handle, mut events) = MarketChannel::connect(config, vec!["1".to_string()]);
+
+        match events.next().await.unwrap() {
+            MarketEvent::Book(book) => {
+                assert_eq!(book.asset_id, "1");
+                assert_eq!(book.bids[0].price, "0.48");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        handle.subscribe(["2"]).unwrap();
+        assert_eq!(events.next().await.unwrap(), MarketEvent::Reconnected);
+        match events.next().await.unwrap() {
+            MarketEvent::LastTradePrice(t) => {
+                assert_eq!(t.asset_id, "2");
+                assert_eq!(t.side, Side::BUY);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        handle.close().unwrap();
+        assert!(events.next().await.is_none());
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn close_interrupts_a_stalled_connect() {
+        // accepts TCP but never answers the websocket handshake
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move { listener.accept().await.unwrap() });
+
+        let config = WsConfig::new(url).with_connect_timeout(Duration::from_secs(60));
+        let (handle, mut events) = MarketChannel::connect(config, vec!["1".to_string()]);
+        let _tcp = server.await.unwrap();
+        handle.close().unwrap();
+        let end = tokio::time::timeout(Duration::from_secs(1), events.next()).await;
+        assert!(end.expect("close ignored while connecting").is_none());
+    }
+
+    #[tokio::test]
+    async fn clamps_zero_ping_interval_and_buffer() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            next_text(&mut ws).await;
+            ws.send(Message::Text(
+                json!({
+                    "event_type": "last_trade_price", "asset_id": "1", "market": "0xm",
+                    "price": "0.5", "side": "BUY", "size": "3", "fee_rate_bps": "0",
+                    "timestamp": "2"
+                })
+                .to_string(),
+            ))
+            .await
+            .unwrap();
+            let _ = ws.next().await;
+        });
+
+        let mut config = WsConfig::new(url).with_ping_interval(Duration::ZERO);
+        config.buffer = 0;
+        config.connect_timeout = Duration::ZERO;
+        let (handle, mut events) = MarketChannel::connect(config, vec!["1".to_string()]);
+        assert!(matches!(
+            events.next().await.unwrap(),
+            MarketEvent::LastTradePrice(_)
+        ));
+        handle.close().unwrap();
+        assert!(events.next().await.is_none());
+        server.await.unwrap();
+    }
+}
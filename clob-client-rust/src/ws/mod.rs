@@ -0,0 +1,387 @@
+//! WebSocket channels of the CLOB (`/ws/market`, `/ws/user`).
+//!
+//! Every channel shares the same connection machinery: a background task owns the socket,
+//! reconnects with exponential backoff, replays the current subscriptions after each reconnect,
+//! sends the textual `PING` heartbeat and forwards parsed events into a bounded queue that the
+//! caller consumes as a `Stream`.
+
+pub mod market;
+pub mod user;
+
+pub use market::*;
+pub use user::*;
+
+use crate::errors::ClobError;
+use futures_util::{SinkExt, Stream, StreamExt};
+use std::collections::BTreeSet;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+pub const WS_MARKET_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+pub const WS_USER_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/user";
+
+/// Connection settings shared by all channels.
+#[derive(Debug, Clone)]
+pub struct WsConfig {
+    /// Full channel URL, e.g. `WS_MARKET_URL` or `WS_USER_URL`.
+    pub url: String,
+    /// Interval of the `PING` heartbeat.
+    pub ping_interval: Duration,
+    /// Reconnect when nothing (not even `PONG`) arrived for this long.
+    pub idle_timeout: Duration,
+    /// First reconnect delay, doubled up to `max_reconnect_delay`.
+    pub min_reconnect_delay: Duration,
+    pub max_reconnect_delay: Duration,
+    /// Events buffered before the socket reader waits for the consumer.
+    pub buffer: usize,
+    /// Give up on a connection attempt (TCP, TLS and handshake) after this long and retry.
+    pub connect_timeout: Duration,
+}
+
+impl WsConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ping_interval: Duration::from_secs(10),
+            idle_timeout: Duration::from_secs(30),
+            min_reconnect_delay: Duration::from_millis(500),
+            max_reconnect_delay: Duration::from_secs(30),
+            buffer: 1024,
+            connect_timeout: Duration::from_secs(10),
+        }
+    }
+
+    pub fn with_ping_interval(mut self, interval: Duration) -> Self {
+        self.ping_interval = interval;
+        self
+    }
+
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    pub fn with_reconnect_delay(mut self, min: Duration, max: Duration) -> Self {
+        self.min_reconnect_delay = min;
+        self.max_reconnect_delay = max;
+        self
+    }
+
+    pub fn with_buffer(mut self, buffer: usize) -> Self {
+        self.buffer = buffer.max(1);
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    // Fields are public: raise values that would panic or spin to their minimum.
+    fn clamped(mut self) -> Self {
+        self.ping_interval = self.ping_interval.max(MIN_INTERVAL);
+        self.connect_timeout = self.connect_timeout.max(MIN_INTERVAL);
+        self.buffer = self.buffer.max(1);
+        self
+    }
+}
+
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Channel specific protocol: subscription messages and event parsing.
+pub(crate) trait Channel: Send + Sync + 'static {
+    type Event: Send + 'static;
+
+    /// First message after (re)connecting, carrying every current subscription.
+    fn initial_message(&self, ids: &[String]) -> String;
+    /// Incremental update on an open connection.
+    fn update_message(&self, ids: &[String], subscribe: bool) -> String;
+    /// Parse one text frame into zero or more events.
+    fn parse(&self, text: &str) -> Vec<Self::Event>;
+    /// Event emitted after a reconnect, so consumers can resync local state.
+    fn reconnected(&self) -> Self::Event;
+}
+
+#[derive(Debug)]
+enum Command {
+    Subscribe(Vec<String>),
+    Unsubscribe(Vec<String>),
+    Close,
+}
+
+/// Handle to a running channel: change subscriptions at runtime or close the connection.
+#[derive(Debug, Clone)]
+pub struct WsHandle {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl WsHandle {
+    pub fn subscribe<I, S>(&self, ids: I) -> Result<(), ClobError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(Command::Subscribe(
+            ids.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    pub fn unsubscribe<I, S>(&self, ids: I) -> Result<(), ClobError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(Command::Unsubscribe(
+            ids.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Close the socket; the event stream ends once buffered events are drained.
+    pub fn close(&self) -> Result<(), ClobError> {
+        self.send(Command::Close)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.commands.is_closed()
+    }
+
+    fn send(&self, cmd: Command) -> Result<(), ClobError> {
+        self.commands
+            .send(cmd)
+            .map_err(|_| ClobError::Transport("websocket connection closed".to_string()))
+    }
+}
+
+/// Stream of channel events. Ends when the connection is closed through its `WsHandle`.
+pub struct WsStream<E> {
+    events: mpsc::Receiver<E>,
+}
+
+impl<E> Stream for WsStream<E> {
+    type Item = E;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<E>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+pub(crate) fn spawn<C: Channel>(
+    config: WsConfig,
+    channel: C,
+    ids: Vec<String>,
+) -> (WsHandle, WsStream<C::Event>) {
+    let config = config.clamped();
+    let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
+    let (ev_tx, ev_rx) = mpsc::channel(config.buffer);
+    tokio::spawn(run(
+        config,
+        channel,
+        ids.into_iter().collect(),
+        cmd_rx,
+        ev_tx,
+    ));
+    (WsHandle { commands: cmd_tx }, WsStream { events: ev_rx })
+}
+
+enum Exit {
+    // Close requested or consumer gone: stop for good.
+    Stop,
+    // Connection lost: reconnect.
+    Reconnect,
+}
+
+async fn run<C: Channel>(
+    config: WsConfig,
+    channel: C,
+    mut subscriptions: BTreeSet<String>,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    events: mpsc::Sender<C::Event>,
+) {
+    let mut delay = config.min_reconnect_delay;
+    let mut connected_before = false;
+    let mut commands_open = true;
+    loop {
+        let connect = tokio::time::timeout(
+            config.connect_timeout,
+            tokio_tungstenite::connect_async(config.url.as_str()),
+        );
+        tokio::pin!(connect);
+        let connected = loop {
+            tokio::select! {
+                res = &mut connect => break res,
+                cmd = commands.recv(), if commands_open => {
+                    if !apply_offline(cmd, &mut subscriptions, &mut commands_open) {
+                        return;
+                    }
+                }
+                _ = events.closed() => return,
+            }
+        };
+        match connected {
+            Ok(Ok((socket, _))) => {
+                tracing::debug!(url = %config.url, "websocket connected");
+                delay = config.min_reconnect_delay;
+                if connected_before && events.send(channel.reconnected()).await.is_err() {
+                    return;
+                }
+                connected_before = true;
+                match session(
+                    &config,
+                    &channel,
+                    socket,
+                    &mut subscriptions,
+                    &mut commands,
+                    &mut commands_open,
+                    &events,
+                )
+                .await
+                {
+                    Exit::Stop => return,
+                    Exit::Reconnect => {
+                        tracing::debug!(url = %config.url, "websocket disconnected, reconnecting")
+                    }
+                }
+            }
+            Ok(Err(e)) => {
+                tracing::warn!(url = %config.url, error = %e, ?delay, "websocket connect failed");
+            }
+            Err(_) => {
+                tracing::warn!(
+                    url = %config.url,
+                    timeout = ?config.connect_timeout,
+                    ?delay,
+                    "websocket connect timed out"
+                );
+            }
+        }
+        // Keep honouring close requests while waiting to reconnect.
+        let sleep = tokio::time::sleep(delay);
+        tokio::pin!(sleep);
+        loop {
+            tokio::select! {
+                _ = &mut sleep => break,
+                cmd = commands.recv(), if commands_open => {
+                    if !apply_offline(cmd, &mut subscriptions, &mut commands_open) {
+                        return;
+                    }
+                }
+                _ = events.closed() => return,
+            }
+        }
+        delay = (delay * 2).min(config.max_reconnect_delay);
+    }
+}
+
+// Apply a command received while no socket is open; `false` once the task should stop.
+fn apply_offline(
+    cmd: Option<Command>,
+    subscriptions: &mut BTreeSet<String>,
+    commands_open: &mut bool,
+) -> bool {
+    match cmd {
+        Some(Command::Close) => return false,
+        Some(Command::Subscribe(ids)) => subscriptions.extend(ids),
+        Some(Command::Unsubscribe(ids)) => {
+            for id in ids {
+                subscriptions.remove(&id);
+            }
+        }
+        None => *commands_open = false,
+    }
+    true
+}
+
+type Socket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn session<C: Channel>(
+    config: &WsConfig,
+    channel: &C,
+    socket: Socket,
+    subscriptions: &mut BTreeSet<String>,
+    commands: &mut mpsc::UnboundedReceiver<Command>,
+    commands_open: &mut bool,
+    events: &mpsc::Sender<C::Event>,
+) -> Exit {
+    let (mut sink, mut source) = socket.split();
+    let ids: Vec<String> = subscriptions.iter().cloned().collect();
+    if let Err(e) = sink
+        .send(Message::Text(channel.initial_message(&ids)))
+        .await
+    {
+        tracing::warn!(error = %e, "websocket subscribe failed");
+        return Exit::Reconnect;
+    }
+    let mut ping = tokio::time::interval(config.ping_interval);
+    ping.tick().await;
+    let mut last_seen = tokio::time::Instant::now();
+    loop {
+        tokio::select! {
+            msg = source.next() => {
+                last_seen = tokio::time::Instant::now();
+                match msg {
+                    Some(Ok(Message::Text(text))) => {
+                        if text == "PONG" {
+                            continue;
+                        }
+                        for ev in channel.parse(&text) {
+                            if events.send(ev).await.is_err() {
+                                let _ = sink.close().await;
+                                return Exit::Stop;
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => return Exit::Reconnect,
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        tracing::warn!(error = %e, "websocket read failed");
+                        return Exit::Reconnect;
+                    }
+                }
+            }
+            cmd = commands.recv(), if *commands_open => {
+                let (ids, subscribe) = match cmd {
+                    Some(Command::Subscribe(ids)) => {
+                        subscriptions.extend(ids.iter().cloned());
+                        (ids, true)
+                    }
+                    Some(Command::Unsubscribe(ids)) => {
+                        for id in ids.iter() {
+                            subscriptions.remove(id);
+                        }
+                        (ids, false)
+                    }
+                    Some(Command::Close) => {
+                        let _ = sink.close().await;
+                        return Exit::Stop;
+                    }
+                    // every handle dropped: keep streaming until the consumer goes away
+                    None => {
+                        *commands_open = false;
+                        continue;
+                    }
+                };
+                if let Err(e) = sink.send(Message::Text(channel.update_message(&ids, subscribe))).await {
+                    tracing::warn!(error = %e, "websocket subscription update failed");
+                    return Exit::Reconnect;
+                }
+            }
+            _ = ping.tick() => {
+                if last_seen.elapsed() > config.idle_timeout {
+                    tracing::warn!(idle = ?last_seen.elapsed(), "websocket heartbeat timed out");
+                    return Exit::Reconnect;
+                }
+                if sink.send(Message::Text("PING".to_string())).await.is_err() {
+                    return Exit::Reconnect;
+                }
+            }
+            _ = events.closed() => {
+                let _ = sink.close().await;
+                return Exit::Stop;
+            }
+        }
+    }
+}
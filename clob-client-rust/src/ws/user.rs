@@ -0,0 +1,254 @@
+use super::{Channel, WsConfig, WsHandle, WsStream};
+use crate::types::{ApiKeyCreds, Side};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Lifecycle step reported by an `order` event.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OrderEventType {
+    Placement,
+    Update,
+    Cancellation,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Settlement status reported by a `trade` event.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TradeStatus {
+    Matched,
+    Mined,
+    Confirmed,
+    Retrying,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TradeStatus {
+    /// `Confirmed` and `Failed` are final; the other states may still change.
+    pub fn is_final(&self) -> bool {
+        matches!(self, TradeStatus::Confirmed | TradeStatus::Failed)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OrderEvent {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: OrderEventType,
+    pub market: String,
+    pub asset_id: String,
+    pub side: Side,
+    pub price: String,
+    #[serde(default)]
+    pub original_size: String,
+    #[serde(default)]
+    pub size_matched: String,
+    #[serde(default)]
+    pub outcome: Option<String>,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub order_type: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub associate_trades: Option<Vec<String>>,
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+/// Maker side of a trade.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MakerOrderFill {
+    pub order_id: String,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub maker_address: Option<String>,
+    pub matched_amount: String,
+    pub price: String,
+    #[serde(default)]
+    pub asset_id: Option<String>,
+    #[serde(default)]
+    pub outcome: Option<String>,
+    #[serde(default)]
+    pub side: Option<Side>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TradeEvent {
+    pub id: String,
+    pub status: TradeStatus,
+    pub market: String,
+    pub asset_id: String,
+    pub side: Side,
+    pub price: String,
+    pub size: String,
+    #[serde(default)]
+    pub taker_order_id: Option<String>,
+    #[serde(default)]
+    pub maker_orders: Vec<MakerOrderFill>,
+    #[serde(default)]
+    pub trader_side: Option<String>,
+    #[serde(default)]
+    pub fee_rate_bps: Option<String>,
+    #[serde(default)]
+    pub outcome: Option<String>,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+    #[serde(default)]
+    pub match_time: Option<String>,
+    #[serde(default)]
+    pub last_update: Option<String>,
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+/// Items yielded by `UserStream`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserEvent {
+    Order(OrderEvent),
+    Trade(TradeEvent),
+    /// The socket was re-established. Updates may have been missed; reconcile with
+    /// `get_open_orders` / `get_trades` if exact state matters.
+    Reconnected,
+}
+
+pub type UserStream = WsStream<UserEvent>;
+
+/// Client for the authenticated user channel, scoped to market (condition) ids.
+pub struct UserChannel;
+
+impl UserChannel {
+    /// Spawn the connection task, authenticate with `creds` and subscribe to `markets`
+    /// (condition ids; an empty list receives events for every market).
+    pub fn connect(
+        config: WsConfig,
+        creds: ApiKeyCreds,
+        markets: Vec<String>,
+    ) -> (WsHandle, UserStream) {
+        super::spawn(config, UserProtocol { creds }, markets)
+    }
+
+    /// `connect` against the production endpoint with default settings.
+    pub fn connect_default(creds: ApiKeyCreds, markets: Vec<String>) -> (WsHandle, UserStream) {
+        Self::connect(WsConfig::new(super::WS_USER_URL), creds, markets)
+    }
+}
+
+struct UserProtocol {
+    creds: ApiKeyCreds,
+}
+
+impl Channel for UserProtocol {
+    type Event = UserEvent;
+
+    fn initial_message(&self, ids: &[String]) -> String {
+        json!({
+            "auth": {
+                "apiKey": self.creds.key,
+                "secret": self.creds.secret,
+                "passphrase": self.creds.passphrase,
+            },
+            "markets": ids,
+            "type": "user",
+        })
+        .to_string()
+    }
+
+    fn update_message(&self, ids: &[String], subscribe: bool) -> String {
+        let operation = if subscribe {
+            "subscribe"
+        } else {
+            "unsubscribe"
+        };
+        json!({ "markets": ids, "operation": operation }).to_string()
+    }
+
+    fn parse(&self, text: &str) -> Vec<UserEvent> {
+        parse_user_message(text)
+    }
+
+    fn reconnected(&self) -> UserEvent {
+        UserEvent::Reconnected
+    }
+}
+
+/// Parse a user channel frame: a single event object or an array of them. Unknown event
+/// types and malformed entries are skipped.
+pub fn parse_user_message(text: &str) -> Vec<UserEvent> {
+    let value: Value = match serde_json::from_str(text) {
+        Ok(v) => v,
+        Err(e) => {
+            tracing::debug!(error = %e, "ignoring non-JSON user message");
+            return Vec::new();
+        }
+    };
+    let items = match value {
+        Value::Array(items) => items,
+        other => vec![other],
+    };
+    items.into_iter().filter_map(parse_user_event).collect()
+}
+
+fn parse_user_event(value: Value) -> Option<UserEvent> {
+    let kind = value.get("event_type")?.as_str()?.to_string();
+    let parsed = match kind.as_str() {
+        "order" => serde_json::from_value(value).map(UserEvent::Order),
+        "trade" => serde_json::from_value(value).map(UserEvent::Trade),
+        _ => {
+            tracing::trace!(event_type = %kind, "ignoring user event");
+            return None;
+        }
+    };
+    parsed
+        .map_err(|e| tracing::debug!(event_type = %kind, error = %e, "malformed user event"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_order_and_trade_events() {
+        let text = json!([
+            {
+                "event_type": "order", "type": "PLACEMENT", "id": "0xo1",
+                "market": "0xm", "asset_id": "1", "side": "BUY", "price": "0.5",
+                "original_size": "10", "size_matched": "0", "timestamp": "1"
+            },
+            {
+                "event_type": "trade", "type": "TRADE", "id": "t1", "status": "MINED",
+                "market": "0xm", "asset_id": "1", "side": "BUY", "price": "0.5", "size": "4",
+                "taker_order_id": "0xo1",
+                "maker_orders": [{"order_id": "0xo2", "matched_amount": "4", "price": "0.5"}]
+            },
+            { "event_type": "something_new" }
+        ])
+        .to_string();
+        let events = parse_user_message(&text);
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            UserEvent::Order(o) => {
+                assert_eq!(o.kind, OrderEventType::Placement);
+                assert_eq!(o.original_size, "10");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match &events[1] {
+            UserEvent::Trade(t) => {
+                assert_eq!(t.status, TradeStatus::Mined);
+                assert!(!t.status.is_final());
+                assert_eq!(t.maker_orders[0].order_id, "0xo2");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! ABI calldata for the CTF Exchange (and neg risk exchange, same interface).
+//!
+//! On-chain the order is the EIP-712 `Order` struct plus its `bytes signature`:
+//! `(uint256 salt, address maker, address signer, address taker, uint256 tokenId,
+//! uint256 makerAmount, uint256 takerAmount, uint256 expiration, uint256 nonce,
+//! uint256 feeRateBps, uint8 side, uint8 signatureType, bytes signature)`.
+
+use crate::Order;
+use ethers::abi::{Token, encode};
+use ethers::types::{Address, U256};
+use ethers::utils::keccak256;
+
+const ORDER_TUPLE: &str = "(uint256,address,address,address,uint256,uint256,uint256,uint256,uint256,uint256,uint8,uint8,bytes)";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CalldataError {
+    #[error("invalid {field}: {value:?}")]
+    InvalidField { field: &'static str, value: String },
+    #[error("{orders} orders but {amounts} fill amounts")]
+    LengthMismatch { orders: usize, amounts: usize },
+}
+
+/// Order with its signature, as passed to the exchange.
+pub type SignedOrderRef<'a> = (&'a Order, &'a str);
+
+fn selector(name: &str, params: &str) -> [u8; 4] {
+    let hash = keccak256(format!(
+        "{}({})",
+        name,
+        params.replace("ORDER", ORDER_TUPLE)
+    ));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn calldata(name: &str, params: &str, args: &[Token]) -> String {
+    let mut data = selector(name, params).to_vec();
+    data.extend(encode(args));
+    format!("0x{}", hex::encode(data))
+}
+
+fn uint(field: &'static str, value: &str) -> Result<U256, CalldataError> {
+    U256::from_dec_str(value).map_err(|_| CalldataError::InvalidField {
+        field,
+        value: value.to_string(),
+    })
+}
+
+fn address(field: &'static str, value: &str) -> Result<Address, CalldataError> {
+    value.parse().map_err(|_| CalldataError::InvalidField {
+        field,
+        value: value.to_string(),
+    })
+}
+
+fn order_token(order: &Order, signature: &str) -> Result<Token, CalldataError> {
+    let signature = hex::decode(signature.trim_start_matches("0x")).map_err(|_| {
+        CalldataError::InvalidField {
+            field: "signature",
+            value: signature.to_string(),
+        }
+    })?;
+    Ok(Token::Tuple(vec![
+        Token::Uint(uint("salt", &order.salt)?),
+        Token::Address(address("maker", &order.maker)?),
+        Token::Address(address("signer", &order.signer)?),
+        Token::Address(address("taker", &order.taker)?),
+        Token::Uint(uint("tokenId", &order.token_id)?),
+        Token::Uint(uint("makerAmount", &order.maker_amount)?),
+        Token::Uint(uint("takerAmount", &order.taker_amount)?),
+        Token::Uint(uint("expiration", &order.expiration)?),
+        Token::Uint(uint("nonce", &order.nonce)?),
+        Token::Uint(uint("feeRateBps", &order.fee_rate_bps)?),
+        Token::Uint(U256::from(order.side)),
+        Token::Uint(U256::from(order.signature_type)),
+        Token::Bytes(signature),
+    ]))
+}
+
+fn order_tokens(orders: &[SignedOrderRef]) -> Result<Token, CalldataError> {
+    let tokens = orders
+        .iter()
+        .map(|(order, sig)| order_token(order, sig))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Token::Array(tokens))
+}
+
+fn amount_tokens<S: AsRef<str>>(amounts: &[S], orders: usize) -> Result<Token, CalldataError> {
+    if amounts.len() != orders {
+        return Err(CalldataError::LengthMismatch {
+            orders,
+            amounts: amounts.len(),
+        });
+    }
+    let tokens = amounts
+        .iter()
+        .map(|a| uint("fillAmount", a.as_ref()).map(Token::Uint))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Token::Array(tokens))
+}
+
+/// `fillOrder(Order order, uint256 fillAmount)`; `fill_amount` in maker amount units.
+pub fn fill_order_calldata(
+    order: &Order,
+    signature: &str,
+    fill_amount: &str,
+) -> Result<String, CalldataError> {
+    Ok(calldata(
+        "fillOrder",
+        "ORDER,uint256",
+        &[
+            order_token(order, signature)?,
+            Token::Uint(uint("fillAmount", fill_amount)?),
+        ],
+    ))
+}
+
+/// `fillOrders(Order[] orders, uint256[] fillAmounts)`.
+pub fn fill_orders_calldata<S: AsRef<str>>(
+    orders: &[SignedOrderRef],
+    fill_amounts: &[S],
+) -> Result<String, CalldataError> {
+    Ok(calldata(
+        "fillOrders",
+        "ORDER[],uint256[]",
+        &[
+            order_tokens(orders)?,
+            amount_tokens(fill_amounts, orders.len())?,
+        ],
+    ))
+}
+
+/// `matchOrders(Order takerOrder, Order[] makerOrders, uint256 takerFillAmount,
+/// uint256[] makerFillAmounts)`.
+pub fn match_orders_calldata<S: AsRef<str>>(
+    taker: SignedOrderRef,
+    makers: &[SignedOrderRef],
+    taker_fill_amount: &str,
+    maker_fill_amounts: &[S],
+) -> Result<String, CalldataError> {
+    Ok(calldata(
+        "matchOrders",
+        "ORDER,ORDER[],uint256,uint256[]",
+        &[
+            order_token(taker.0, taker.1)?,
+            order_tokens(makers)?,
+            Token::Uint(uint("takerFillAmount", taker_fill_amount)?),
+            amount_tokens(maker_fill_amounts, makers.len())?,
+        ],
+    ))
+}
+
+/// `cancelOrder(Order order)`; must be sent by the order maker.
+pub fn cancel_order_calldata(order: &Order, signature: &str) -> Result<String, CalldataError> {
+    Ok(calldata(
+        "cancelOrder",
+        "ORDER",
+        &[order_token(order, signature)?],
+    ))
+}
+
+/// `cancelOrders(Order[] orders)`; must be sent by the orders' maker.
+pub fn cancel_orders_calldata(orders: &[SignedOrderRef]) -> Result<String, CalldataError> {
+    Ok(calldata(
+        "cancelOrders",
+        "ORDER[]",
+        &[order_tokens(orders)?],
+    ))
+}
+
+/// `incrementNonce()`: invalidates every order of the sender signed with the current nonce.
+pub fn increment_nonce_calldata() -> String {
+    calldata("incrementNonce", "", &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::abi::{AbiParser, Tokenizable};
+
+    fn order(side: u8) -> Order {
+        Order {
+            salt: "479249096354".into(),
+            maker: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".into(),
+            signer: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".into(),
+            taker: crate::ZERO_ADDRESS.into(),
+            token_id: "1234".into(),
+            maker_amount: "5050000".into(),
+            taker_amount: "10000000".into(),
+            expiration: "0".into(),
+            nonce: "0".into(),
+            fee_rate_bps: "0".into(),
+            side,
+            signature_type: 0,
+        }
+    }
+
+    #[test]
+    fn encodes_exchange_calls() {
+        let abi = AbiParser::default()
+            .parse(&[
+                "struct Order { uint256 salt; address maker; address signer; address taker; uint256 tokenId; uint256 makerAmount; uint256 takerAmount; uint256 expiration; uint256 nonce; uint256 feeRateBps; uint8 side; uint8 signatureType; bytes signature; }",
+                "function fillOrder(Order order, uint256 fillAmount)",
+                "function fillOrders(Order[] orders, uint256[] fillAmounts)",
+                "function matchOrders(Order takerOrder, Order[] makerOrders, uint256 takerFillAmount, uint256[] makerFillAmounts)",
+                "function cancelOrder(Order order)",
+                "function cancelOrders(Order[] orders)",
+                "function incrementNonce()",
+            ])
+            .unwrap();
+        let sig = format!("0x{}1b", "ab".repeat(64));
+        let (buy, sell) = (order(0), order(1));
+        let decode = |name: &str, data: &str| {
+            let bytes = hex::decode(&data[2..]).unwrap();
+            let f = abi.function(name).unwrap();
+            assert_eq!(bytes[..4], f.short_signature(), "{}", name);
+            f.decode_input(&bytes[4..]).unwrap()
+        };
+
+        // selectors of the deployed CTF Exchange
+        for (name, data, selector) in [
+            (
+                "fillOrder",
+                fill_order_calldata(&buy, &sig, "100").unwrap(),
+                "0xfe729aaf",
+            ),
+            (
+                "fillOrders",
+                fill_orders_calldata(&[(&buy, &sig)], &["1"]).unwrap(),
+                "0xd798eff6",
+            ),
+            (
+                "matchOrders",
+                match_orders_calldata((&buy, &sig), &[(&sell, &sig)], "1", &["1"]).unwrap(),
+                "0xe60f0c05",
+            ),
+            (
+                "cancelOrder",
+                cancel_order_calldata(&buy, &sig).unwrap(),
+                "0xa6dfcf86",
+            ),
+            (
+                "cancelOrders",
+                cancel_orders_calldata(&[(&buy, &sig), (&sell, &sig)]).unwrap(),
+                "0xfa950b48",
+            ),
+            ("incrementNonce", increment_nonce_calldata(), "0x627cdcb9"),
+        ] {
+            assert_eq!(&data[..10], selector, "{}", name);
+            decode(name, &data);
+        }
+
+        let data = match_orders_calldata(
+            (&buy, &sig),
+            &[(&sell, &sig), (&sell, "0x")],
+            "100",
+            &["60", "40"],
+        )
+        .unwrap();
+        let args = decode("matchOrders", &data);
+        let makers = args[1].clone().into_array().unwrap();
+        assert_eq!(makers.len(), 2);
+        let maker = makers[0].clone().into_tuple().unwrap();
+        assert_eq!(maker[10], Token::Uint(1.into()));
+        assert_eq!(maker[12], Token::Bytes(hex::decode(&sig[2..]).unwrap()));
+        assert_eq!(args[2], U256::from(100).into_token());
+        assert_eq!(
+            args[3],
+            Token::Array(vec![
+                U256::from(60).into_token(),
+                U256::from(40).into_token()
+            ])
+        );
+
+        assert!(matches!(
+            fill_orders_calldata(&[(&buy, &sig)], &["1", "2"]),
+            Err(CalldataError::LengthMismatch {
+                orders: 1,
+                amounts: 2
+            })
+        ));
+        assert!(matches!(
+            fill_order_calldata(&buy, &sig, "-1"),
+            Err(CalldataError::InvalidField {
+                field: "fillAmount",
+                ..
+            })
+        ));
+    }
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+pub mod calldata;
+
+pub use calldata::*;
+
 pub const PROTOCOL_NAME: &str = "Polymarket CTF Exchange";
 pub const PROTOCOL_VERSION: &str = "1";
 pub const ZX: &str = "0x";